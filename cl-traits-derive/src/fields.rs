@@ -0,0 +1,90 @@
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Member, Type};
+
+/// Field that receives forwarded calls.
+pub(crate) struct Field<'any> {
+  pub(crate) member: Member,
+  pub(crate) ty: &'any Type,
+}
+
+/// Fields that receive forwarded calls.
+pub(crate) enum Target<'any> {
+  /// All fields of a multi-field structure without a marked field.
+  All(Vec<Field<'any>>),
+  /// A single field or the field marked with `#[cl_traits(delegate)]`.
+  Delegate {
+    /// The delegate
+    field: Field<'any>,
+    /// Every other field
+    others: Vec<Field<'any>>,
+  },
+}
+
+/// Finds out which fields of `input` should receive the forwarded calls of `trait_name`.
+///
+/// `aggregates` tells whether `trait_name` can be derived by combining all fields when none of
+/// them is marked.
+pub(crate) fn target<'any>(
+  input: &'any DeriveInput,
+  trait_name: &str,
+  aggregates: bool,
+) -> syn::Result<Target<'any>> {
+  let fields = match input.data {
+    Data::Struct(ref elem) => &elem.fields,
+    _ => {
+      return Err(Error::new(
+        input.ident.span(),
+        format!("`{}` can only be derived for structures", trait_name),
+      ))
+    }
+  };
+  let mut all = Vec::new();
+  let mut marked = None;
+  for (idx, field) in fields.iter().enumerate() {
+    let member = match field.ident {
+      Some(ref ident) => Member::Named(ident.clone()),
+      None => Member::Unnamed(idx.into()),
+    };
+    if is_delegate(field)? {
+      if marked.is_some() {
+        return Err(Error::new(field.span(), "only one field can be marked as a delegate"));
+      }
+      marked = Some(all.len());
+    }
+    all.push(Field { member, ty: &field.ty });
+  }
+  if let Some(idx) = marked {
+    let field = all.remove(idx);
+    return Ok(Target::Delegate { field, others: all });
+  }
+  match (&fields, all.len()) {
+    (Fields::Unit, _) | (_, 0) => Err(Error::new(
+      input.ident.span(),
+      format!("`{}` can't be derived for structures without fields", trait_name),
+    )),
+    (_, 1) => Ok(Target::Delegate { field: all.remove(0), others: Vec::new() }),
+    _ if aggregates => Ok(Target::All(all)),
+    _ => Err(Error::new(
+      Span::call_site(),
+      format!(
+        "`{}` needs one of the fields to be marked with `#[cl_traits(delegate)]`",
+        trait_name
+      ),
+    )),
+  }
+}
+
+fn is_delegate(field: &syn::Field) -> syn::Result<bool> {
+  let mut rslt = false;
+  for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("cl_traits")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("delegate") {
+        rslt = true;
+        Ok(())
+      } else {
+        Err(meta.error("unknown `cl_traits` attribute, expected `delegate`"))
+      }
+    })?;
+  }
+  Ok(rslt)
+}
@@ -0,0 +1,779 @@
+//! Derive macros for `cl-traits`.
+//!
+//! Every trait of `cl-traits` can be derived for structures that wrap other collections. Calls are
+//! forwarded to the only field of the structure or to the field marked with
+//! `#[cl_traits(delegate)]`.
+//!
+//! `Capacity`, `CapacityUpperBound`, `Clear`, `ConstCapacity` and `Length` can also be derived
+//! for multi-field structures without a marked field, in which case the results of all fields are
+//! summed (or all fields are cleared).
+//!
+//! ```rust
+//! use cl_traits::{Length, Push};
+//!
+//! #[derive(Default, cl_traits::Length, cl_traits::Push)]
+//! struct Stack {
+//!   #[cl_traits(delegate)]
+//!   data: Vec<i32>,
+//!   label: &'static str,
+//! }
+//!
+//! let mut stack = Stack::default();
+//! stack.push(1).unwrap();
+//! assert_eq!(stack.length(), 1);
+//! ```
+
+extern crate proc_macro;
+
+mod fields;
+
+use fields::{target, Field, Target};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, DeriveInput, Ident};
+
+macro_rules! derive {
+  ($(#[$doc:meta])* $fn_name:ident, $trait_name:ident, $aggregates:expr, $cb:ident) => {
+    $(#[$doc])*
+    #[proc_macro_derive($trait_name, attributes(cl_traits))]
+    pub fn $fn_name(input: TokenStream) -> TokenStream {
+      let input = parse_macro_input!(input as DeriveInput);
+      expand(&input, stringify!($trait_name), $aggregates, $cb)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+    }
+  };
+}
+
+derive!(
+  /// Derives `Capacity`, summing the capacities of all fields when none of them is a delegate.
+  capacity,
+  Capacity,
+  true,
+  capacity_body
+);
+derive!(
+  /// Derives `CapacityUpperBound`, summing the bounds of all fields when none of them is a
+  /// delegate.
+  ///
+  /// ```rust
+  /// use cl_traits::CapacityUpperBound;
+  ///
+  /// #[derive(cl_traits::CapacityUpperBound)]
+  /// struct Units(Vec<()>, Vec<()>);
+  ///
+  /// assert_eq!(Units(Vec::new(), Vec::new()).capacity_upper_bound(), usize::MAX);
+  /// ```
+  capacity_upper_bound,
+  CapacityUpperBound,
+  true,
+  capacity_upper_bound_body
+);
+derive!(
+  /// Derives `Clear`, clearing all fields when none of them is a delegate.
+  clear,
+  Clear,
+  true,
+  clear_body
+);
+derive!(
+  /// Derives `ConstCapacity`, summing the capacities of all fields when none of them is a delegate.
+  const_capacity,
+  ConstCapacity,
+  true,
+  const_capacity_body
+);
+derive!(
+  /// Derives `Get`.
+  get,
+  Get,
+  false,
+  get_body
+);
+derive!(
+  /// Derives `GetMut`.
+  get_mut,
+  GetMut,
+  false,
+  get_mut_body
+);
+derive!(
+  /// Derives `Insert`.
+  insert,
+  Insert,
+  false,
+  insert_body
+);
+derive!(
+  /// Derives `Iter`.
+  iter,
+  Iter,
+  false,
+  iter_body
+);
+derive!(
+  /// Derives `IterMut`.
+  iter_mut,
+  IterMut,
+  false,
+  iter_mut_body
+);
+derive!(
+  /// Derives `Length`, summing the lengths of all fields when none of them is a delegate.
+  ///
+  /// ```rust
+  /// use cl_traits::Length;
+  ///
+  /// #[derive(cl_traits::Length)]
+  /// struct Pair(Vec<i32>, Option<i32>);
+  ///
+  /// assert_eq!(Pair(vec![1, 2], Some(3)).length(), 3);
+  ///
+  /// #[derive(cl_traits::Length)]
+  /// struct Labeled {
+  ///   label: String,
+  ///   #[cl_traits(delegate)]
+  ///   data: Vec<i32>,
+  /// }
+  ///
+  /// assert_eq!(Labeled { label: "abc".into(), data: vec![1] }.length(), 1);
+  /// ```
+  length,
+  Length,
+  true,
+  length_body
+);
+derive!(
+  /// Derives `Pop`.
+  pop,
+  Pop,
+  false,
+  pop_body
+);
+derive!(
+  /// Derives `PopFront`.
+  pop_front,
+  PopFront,
+  false,
+  pop_front_body
+);
+derive!(
+  /// Derives `Push`.
+  ///
+  /// Multi-field structures must mark exactly one field as the delegate.
+  ///
+  /// ```compile_fail
+  /// #[derive(cl_traits::Push)]
+  /// struct Pair(Vec<i32>, Vec<i32>);
+  /// ```
+  ///
+  /// ```compile_fail
+  /// #[derive(cl_traits::Push)]
+  /// struct Pair(#[cl_traits(delegate)] Vec<i32>, #[cl_traits(delegate)] Vec<i32>);
+  /// ```
+  push,
+  Push,
+  false,
+  push_body
+);
+derive!(
+  /// Derives `PushChar`.
+  push_char,
+  PushChar,
+  false,
+  push_char_body
+);
+derive!(
+  /// Derives `PushFront`.
+  push_front,
+  PushFront,
+  false,
+  push_front_body
+);
+derive!(
+  /// Derives `PushMany`. The structure must also implement `Storage`, e.g., through its derive.
+  push_many,
+  PushMany,
+  false,
+  push_many_body
+);
+derive!(
+  /// Derives `PushStr`.
+  push_str,
+  PushStr,
+  false,
+  push_str_body
+);
+derive!(
+  /// Derives `Remove`.
+  remove,
+  Remove,
+  false,
+  remove_body
+);
+derive!(
+  /// Derives `Reserve`.
+  reserve,
+  Reserve,
+  false,
+  reserve_body
+);
+derive!(
+  /// Derives `ReserveExact`.
+  reserve_exact,
+  ReserveExact,
+  false,
+  reserve_exact_body
+);
+derive!(
+  /// Derives `Retain`.
+  ///
+  /// ```rust
+  /// use cl_traits::Retain;
+  ///
+  /// #[derive(cl_traits::Retain)]
+  /// struct Words<'any>(Vec<&'any str>);
+  ///
+  /// let text = String::from("a bb ccc");
+  /// let mut words = Words(text.split(' ').collect());
+  /// words.retain(|word| word.len() > 1);
+  /// assert_eq!(words.0, ["bb", "ccc"]);
+  /// ```
+  retain,
+  Retain,
+  false,
+  retain_body
+);
+derive!(
+  /// Derives `RetainMap`.
+  retain_map,
+  RetainMap,
+  false,
+  retain_map_body
+);
+derive!(
+  /// Derives `RetainMut`.
+  retain_mut,
+  RetainMut,
+  false,
+  retain_mut_body
+);
+derive!(
+  /// Derives `ShrinkToFit`.
+  shrink_to_fit,
+  ShrinkToFit,
+  false,
+  shrink_to_fit_body
+);
+derive!(
+  /// Derives `Storage`.
+  storage,
+  Storage,
+  false,
+  storage_body
+);
+derive!(
+  /// Derives `Swap`.
+  swap,
+  Swap,
+  false,
+  swap_body
+);
+derive!(
+  /// Derives `Truncate`.
+  truncate,
+  Truncate,
+  false,
+  truncate_body
+);
+derive!(
+  /// Derives `TryReserve`.
+  try_reserve,
+  TryReserve,
+  false,
+  try_reserve_body
+);
+derive!(
+  /// Derives `TryWithCapacity`. Fields other than the delegate are created with `Default`.
+  try_with_capacity,
+  TryWithCapacity,
+  false,
+  try_with_capacity_body
+);
+derive!(
+  /// Derives `WithCapacity`. Fields other than the delegate are created with `Default`.
+  with_capacity,
+  WithCapacity,
+  false,
+  with_capacity_body
+);
+
+type Body = fn(&Target<'_>, &mut Vec<syn::WherePredicate>) -> TokenStream2;
+
+fn expand(
+  input: &DeriveInput,
+  trait_name: &str,
+  aggregates: bool,
+  body: Body,
+) -> syn::Result<TokenStream2> {
+  let target = target(input, trait_name, aggregates)?;
+  let trait_ident = Ident::new(trait_name, proc_macro2::Span::call_site());
+  let mut predicates = Vec::new();
+  match target {
+    Target::All(ref fields) => {
+      for Field { ty, .. } in fields {
+        predicates.push(parse_quote!(#ty: ::cl_traits::#trait_ident));
+      }
+    }
+    Target::Delegate { ref field, .. } => {
+      let ty = field.ty;
+      predicates.push(parse_quote!(#ty: ::cl_traits::#trait_ident));
+    }
+  }
+  let tokens = body(&target, &mut predicates);
+  let mut generics = input.generics.clone();
+  generics.make_where_clause().predicates.extend(predicates);
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+  let ident = &input.ident;
+  Ok(quote! {
+    impl #impl_generics ::cl_traits::#trait_ident for #ident #ty_generics #where_clause {
+      #tokens
+    }
+  })
+}
+
+fn aggregate(target: &Target<'_>, f: impl Fn(&Field<'_>) -> TokenStream2) -> TokenStream2 {
+  match *target {
+    Target::All(ref fields) => {
+      let values = fields.iter().map(f);
+      quote! { 0usize #(.saturating_add(#values))* }
+    }
+    Target::Delegate { ref field, .. } => f(field),
+  }
+}
+
+fn delegate<'any>(target: &'any Target<'any>) -> &'any Field<'any> {
+  match *target {
+    Target::All(_) => unreachable!("only aggregating traits accept all fields"),
+    Target::Delegate { ref field, .. } => field,
+  }
+}
+
+fn capacity_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let value = aggregate(target, |Field { member, .. }| {
+    quote! { ::cl_traits::Capacity::capacity(&self.#member) }
+  });
+  quote! {
+    #[inline]
+    fn capacity(&self) -> usize {
+      #value
+    }
+  }
+}
+
+fn capacity_upper_bound_body(
+  target: &Target<'_>,
+  _: &mut Vec<syn::WherePredicate>,
+) -> TokenStream2 {
+  let value = aggregate(target, |Field { member, .. }| {
+    quote! { ::cl_traits::CapacityUpperBound::capacity_upper_bound(&self.#member) }
+  });
+  quote! {
+    #[inline]
+    fn capacity_upper_bound(&self) -> usize {
+      #value
+    }
+  }
+}
+
+fn clear_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let members: Vec<_> = match *target {
+    Target::All(ref fields) => fields.iter().map(|field| &field.member).collect(),
+    Target::Delegate { ref field, .. } => vec![&field.member],
+  };
+  quote! {
+    #[inline]
+    fn clear(&mut self) {
+      #( ::cl_traits::Clear::clear(&mut self.#members); )*
+    }
+  }
+}
+
+fn const_capacity_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let value = match *target {
+    Target::All(ref fields) => {
+      let tys = fields.iter().map(|field| field.ty);
+      quote! { 0usize #(.saturating_add(<#tys as ::cl_traits::ConstCapacity>::CAPACITY))* }
+    }
+    Target::Delegate { ref field, .. } => {
+      let ty = field.ty;
+      quote! { <#ty as ::cl_traits::ConstCapacity>::CAPACITY }
+    }
+  };
+  quote! {
+    const CAPACITY: usize = #value;
+  }
+}
+
+fn get_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Error = <#ty as ::cl_traits::Get>::Error;
+    type Input<'input> = <#ty as ::cl_traits::Get>::Input<'input> where Self: 'input;
+    type Ok<'ok> = <#ty as ::cl_traits::Get>::Ok<'ok> where Self: 'ok;
+
+    #[inline]
+    fn get<'input>(
+      &self,
+      input: Self::Input<'input>
+    ) -> ::core::result::Result<Self::Ok<'_>, Self::Error>
+    where
+      Self: 'input
+    {
+      ::cl_traits::Get::get(&self.#member, input)
+    }
+  }
+}
+
+fn get_mut_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Error = <#ty as ::cl_traits::GetMut>::Error;
+    type Input<'input> = <#ty as ::cl_traits::GetMut>::Input<'input> where Self: 'input;
+    type Ok<'ok> = <#ty as ::cl_traits::GetMut>::Ok<'ok> where Self: 'ok;
+
+    #[inline]
+    fn get_mut<'input>(
+      &mut self,
+      input: Self::Input<'input>
+    ) -> ::core::result::Result<Self::Ok<'_>, Self::Error>
+    where
+      Self: 'input
+    {
+      ::cl_traits::GetMut::get_mut(&mut self.#member, input)
+    }
+  }
+}
+
+fn insert_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  fallible_with_input(target, quote!(Insert), quote!(insert))
+}
+
+fn iter_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Output<'output> = <#ty as ::cl_traits::Iter>::Output<'output> where Self: 'output;
+
+    #[inline]
+    fn iter(&self) -> Self::Output<'_> {
+      ::cl_traits::Iter::iter(&self.#member)
+    }
+  }
+}
+
+fn iter_mut_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Output<'output> = <#ty as ::cl_traits::IterMut>::Output<'output> where Self: 'output;
+
+    #[inline]
+    fn iter_mut(&mut self) -> Self::Output<'_> {
+      ::cl_traits::IterMut::iter_mut(&mut self.#member)
+    }
+  }
+}
+
+fn length_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let value = aggregate(target, |Field { member, .. }| {
+    quote! { ::cl_traits::Length::length(&self.#member) }
+  });
+  quote! {
+    #[inline]
+    fn length(&self) -> usize {
+      #value
+    }
+  }
+}
+
+fn pop_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  fallible_without_input(target, quote!(Pop), quote!(pop))
+}
+
+fn pop_front_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  fallible_without_input(target, quote!(PopFront), quote!(pop_front))
+}
+
+fn push_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  fallible_with_input(target, quote!(Push), quote!(push))
+}
+
+fn push_char_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Error = <#ty as ::cl_traits::PushChar>::Error;
+    type Ok = <#ty as ::cl_traits::PushChar>::Ok;
+
+    #[inline]
+    fn push_char(&mut self, input: char) -> ::core::result::Result<Self::Ok, Self::Error> {
+      ::cl_traits::PushChar::push_char(&mut self.#member, input)
+    }
+  }
+}
+
+fn push_front_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  fallible_with_input(target, quote!(PushFront), quote!(push_front))
+}
+
+fn push_many_body(target: &Target<'_>, predicates: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  predicates
+    .push(parse_quote!(Self: ::cl_traits::Storage<Item = <#ty as ::cl_traits::Storage>::Item>));
+  quote! {
+    #[inline]
+    fn push_many<I>(
+      &mut self,
+      input: I,
+    ) -> ::core::result::Result<
+      (),
+      ::cl_traits::PushManyError<::core::iter::Peekable<I::IntoIter>>
+    >
+    where
+      I: ::core::iter::IntoIterator<Item = Self::Item>,
+    {
+      ::cl_traits::PushMany::push_many(&mut self.#member, input)
+    }
+
+    #[inline]
+    fn push_slice<'input>(
+      &mut self,
+      input: &'input [Self::Item],
+    ) -> ::core::result::Result<(), ::cl_traits::PushManyError<&'input [Self::Item]>>
+    where
+      Self::Item: ::core::clone::Clone,
+    {
+      ::cl_traits::PushMany::push_slice(&mut self.#member, input)
+    }
+  }
+}
+
+fn push_str_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Error<'input> = <#ty as ::cl_traits::PushStr>::Error<'input>;
+    type Ok = <#ty as ::cl_traits::PushStr>::Ok;
+
+    #[inline]
+    fn push_str<'input>(
+      &mut self,
+      input: &'input str
+    ) -> ::core::result::Result<Self::Ok, Self::Error<'input>> {
+      ::cl_traits::PushStr::push_str(&mut self.#member, input)
+    }
+  }
+}
+
+fn remove_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Error = <#ty as ::cl_traits::Remove>::Error;
+    type Input<'input> = <#ty as ::cl_traits::Remove>::Input<'input> where Self: 'input;
+    type Ok = <#ty as ::cl_traits::Remove>::Ok;
+
+    #[inline]
+    fn remove<'input>(
+      &mut self,
+      input: Self::Input<'input>
+    ) -> ::core::result::Result<Self::Ok, Self::Error>
+    where
+      Self: 'input
+    {
+      ::cl_traits::Remove::remove(&mut self.#member, input)
+    }
+  }
+}
+
+fn reserve_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  fallible_with_input(target, quote!(Reserve), quote!(reserve))
+}
+
+fn reserve_exact_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  fallible_with_input(target, quote!(ReserveExact), quote!(reserve_exact))
+}
+
+fn retain_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Input = <#ty as ::cl_traits::Retain>::Input;
+    type Output = <#ty as ::cl_traits::Retain>::Output;
+
+    #[inline]
+    fn retain<F>(&mut self, input: F) -> Self::Output
+    where
+      F: FnMut(&Self::Input) -> bool,
+    {
+      ::cl_traits::Retain::retain(&mut self.#member, input)
+    }
+  }
+}
+
+fn retain_map_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Key = <#ty as ::cl_traits::RetainMap>::Key;
+    type Output = <#ty as ::cl_traits::RetainMap>::Output;
+    type Value = <#ty as ::cl_traits::RetainMap>::Value;
+
+    #[inline]
+    fn retain_map<F>(&mut self, input: F) -> Self::Output
+    where
+      F: FnMut(&Self::Key, &mut Self::Value) -> bool,
+    {
+      ::cl_traits::RetainMap::retain_map(&mut self.#member, input)
+    }
+  }
+}
+
+fn retain_mut_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Input = <#ty as ::cl_traits::RetainMut>::Input;
+    type Output = <#ty as ::cl_traits::RetainMut>::Output;
+
+    #[inline]
+    fn retain_mut<F>(&mut self, input: F) -> Self::Output
+    where
+      F: FnMut(&mut Self::Input) -> bool,
+    {
+      ::cl_traits::RetainMut::retain_mut(&mut self.#member, input)
+    }
+  }
+}
+
+fn shrink_to_fit_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  infallible_with_input(target, quote!(ShrinkToFit), quote!(shrink_to_fit))
+}
+
+fn storage_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  let Field { ty, .. } = delegate(target);
+  quote! {
+    type Item = <#ty as ::cl_traits::Storage>::Item;
+  }
+}
+
+fn swap_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  infallible_with_input(target, quote!(Swap), quote!(swap))
+}
+
+fn truncate_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  infallible_with_input(target, quote!(Truncate), quote!(truncate))
+}
+
+fn try_reserve_body(target: &Target<'_>, _: &mut Vec<syn::WherePredicate>) -> TokenStream2 {
+  fallible_with_input(target, quote!(TryReserve), quote!(try_reserve))
+}
+
+fn try_with_capacity_body(
+  target: &Target<'_>,
+  predicates: &mut Vec<syn::WherePredicate>,
+) -> TokenStream2 {
+  let (Field { member, ty }, others_members) = defaulted_others(target, predicates);
+  quote! {
+    type Error = <#ty as ::cl_traits::TryWithCapacity>::Error;
+    type Input = <#ty as ::cl_traits::TryWithCapacity>::Input;
+
+    #[inline]
+    fn try_with_capacity(input: Self::Input) -> ::core::result::Result<Self, Self::Error> {
+      ::core::result::Result::Ok(Self {
+        #member: ::cl_traits::TryWithCapacity::try_with_capacity(input)?,
+        #( #others_members: ::core::default::Default::default(), )*
+      })
+    }
+  }
+}
+
+fn with_capacity_body(
+  target: &Target<'_>,
+  predicates: &mut Vec<syn::WherePredicate>,
+) -> TokenStream2 {
+  let (Field { member, ty }, others_members) = defaulted_others(target, predicates);
+  quote! {
+    type Input = <#ty as ::cl_traits::WithCapacity>::Input;
+
+    #[inline]
+    fn with_capacity(input: Self::Input) -> Self {
+      Self {
+        #member: ::cl_traits::WithCapacity::with_capacity(input),
+        #( #others_members: ::core::default::Default::default(), )*
+      }
+    }
+  }
+}
+
+// Constructors build the delegate and fill every other field with `Default`
+fn defaulted_others<'any>(
+  target: &'any Target<'any>,
+  predicates: &mut Vec<syn::WherePredicate>,
+) -> (&'any Field<'any>, Vec<&'any syn::Member>) {
+  let (field, others) = match *target {
+    Target::All(_) => unreachable!("only aggregating traits accept all fields"),
+    Target::Delegate { ref field, ref others } => (field, others),
+  };
+  for other in others {
+    let other_ty = other.ty;
+    predicates.push(parse_quote!(#other_ty: ::core::default::Default));
+  }
+  (field, others.iter().map(|other| &other.member).collect())
+}
+
+fn fallible_with_input(
+  target: &Target<'_>,
+  trait_ident: TokenStream2,
+  method: TokenStream2,
+) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Error = <#ty as ::cl_traits::#trait_ident>::Error;
+    type Input = <#ty as ::cl_traits::#trait_ident>::Input;
+    type Ok = <#ty as ::cl_traits::#trait_ident>::Ok;
+
+    #[inline]
+    fn #method(&mut self, input: Self::Input) -> ::core::result::Result<Self::Ok, Self::Error> {
+      ::cl_traits::#trait_ident::#method(&mut self.#member, input)
+    }
+  }
+}
+
+fn fallible_without_input(
+  target: &Target<'_>,
+  trait_ident: TokenStream2,
+  method: TokenStream2,
+) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Error = <#ty as ::cl_traits::#trait_ident>::Error;
+    type Ok = <#ty as ::cl_traits::#trait_ident>::Ok;
+
+    #[inline]
+    fn #method(&mut self) -> ::core::result::Result<Self::Ok, Self::Error> {
+      ::cl_traits::#trait_ident::#method(&mut self.#member)
+    }
+  }
+}
+
+fn infallible_with_input(
+  target: &Target<'_>,
+  trait_ident: TokenStream2,
+  method: TokenStream2,
+) -> TokenStream2 {
+  let Field { member, ty } = delegate(target);
+  quote! {
+    type Input = <#ty as ::cl_traits::#trait_ident>::Input;
+    type Output = <#ty as ::cl_traits::#trait_ident>::Output;
+
+    #[inline]
+    fn #method(&mut self, input: Self::Input) -> Self::Output {
+      ::cl_traits::#trait_ident::#method(&mut self.#member, input)
+    }
+  }
+}
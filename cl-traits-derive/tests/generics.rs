@@ -0,0 +1,108 @@
+//! Derives traits with generic associated types, extra predicates or defaulted fields for
+//! structures that have their own type and lifetime parameters.
+
+use cl_traits::{
+  Get, GetMut, Iter, IterMut, Length, PushMany, PushStr, Remove, TryWithCapacity, WithCapacity,
+};
+
+#[derive(
+  cl_traits::Get,
+  cl_traits::GetMut,
+  cl_traits::Iter,
+  cl_traits::IterMut,
+  cl_traits::Length,
+  cl_traits::PushMany,
+  cl_traits::Remove,
+  cl_traits::Storage,
+  cl_traits::TryWithCapacity,
+  cl_traits::WithCapacity,
+)]
+struct Owned<T> {
+  #[cl_traits(delegate)]
+  data: Vec<T>,
+  label: Option<String>,
+}
+
+#[derive(
+  cl_traits::Get,
+  cl_traits::GetMut,
+  cl_traits::Iter,
+  cl_traits::IterMut,
+  cl_traits::Length,
+  cl_traits::PushMany,
+  cl_traits::Remove,
+  cl_traits::Storage,
+)]
+struct Borrowed<'data, T> {
+  #[cl_traits(delegate)]
+  data: &'data mut Vec<T>,
+  label: &'data str,
+}
+
+#[derive(cl_traits::Length, cl_traits::PushStr, cl_traits::WithCapacity)]
+struct Text<S> {
+  #[cl_traits(delegate)]
+  data: S,
+  edits: usize,
+}
+
+#[derive(cl_traits::Length, cl_traits::PushStr)]
+struct BorrowedText<'data> {
+  #[cl_traits(delegate)]
+  data: &'data mut String,
+  label: &'data str,
+}
+
+#[test]
+fn borrowed() {
+  let mut data = vec![1, 2];
+  let mut structure = Borrowed { data: &mut data, label: "borrowed" };
+  structure.push_many([3, 4]).unwrap();
+  structure.push_slice(&[5]).unwrap();
+  assert_eq!(structure.get(0), Ok(&1));
+  *structure.get_mut(1).unwrap() = 20;
+  structure.iter_mut().for_each(|elem| *elem += 1);
+  assert_eq!(structure.remove(0), Ok(2));
+  assert_eq!(structure.iter().copied().collect::<Vec<_>>(), [21, 4, 5, 6]);
+  assert_eq!(structure.length(), 4);
+  assert_eq!(structure.label, "borrowed");
+  assert_eq!(data, [21, 4, 5, 6]);
+}
+
+#[test]
+fn borrowed_text() {
+  let mut data = String::from("a");
+  let mut structure = BorrowedText { data: &mut data, label: "borrowed" };
+  structure.push_str("bc").unwrap();
+  assert_eq!(structure.length(), 3);
+  assert_eq!(structure.label, "borrowed");
+  assert_eq!(data, "abc");
+}
+
+#[test]
+fn owned() {
+  let mut structure = Owned::<i32>::with_capacity(4);
+  assert!(structure.data.capacity() >= 4);
+  assert_eq!(structure.label, None);
+  structure.push_many([1, 2, 3]).unwrap();
+  structure.push_slice(&[4]).unwrap();
+  assert_eq!(structure.get(3), Ok(&4));
+  *structure.get_mut(0).unwrap() = 10;
+  structure.iter_mut().for_each(|elem| *elem *= 2);
+  assert_eq!(structure.remove(1), Ok(4));
+  assert_eq!(structure.iter().copied().collect::<Vec<_>>(), [20, 6, 8]);
+
+  let structure = Owned::<i32>::try_with_capacity(2).unwrap();
+  assert!(structure.data.capacity() >= 2);
+  assert_eq!(structure.label, None);
+  assert_eq!(structure.length(), 0);
+}
+
+#[test]
+fn text() {
+  let mut structure = Text::<String>::with_capacity(4);
+  assert_eq!(structure.edits, 0);
+  structure.push_str("abc").unwrap();
+  assert_eq!(structure.length(), 3);
+  assert_eq!(structure.data, "abc");
+}
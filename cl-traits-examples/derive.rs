@@ -0,0 +1,95 @@
+//! Derived trait implementations.
+
+use cl_traits::*;
+
+#[derive(
+  Capacity,
+  CapacityUpperBound,
+  Clear,
+  Get,
+  GetMut,
+  Insert,
+  Iter,
+  IterMut,
+  Length,
+  Pop,
+  PopFront,
+  Push,
+  PushFront,
+  PushMany,
+  Remove,
+  Reserve,
+  ReserveExact,
+  Retain,
+  RetainMut,
+  ShrinkToFit,
+  Storage,
+  Swap,
+  Truncate,
+  TryReserve,
+  TryWithCapacity,
+  WithCapacity,
+)]
+struct Queue<T> {
+  #[cl_traits(delegate)]
+  data: std::collections::VecDeque<T>,
+  label: String,
+}
+
+#[derive(Capacity, Clear, Length)]
+struct Pair(Vec<i32>, Vec<i32>);
+
+#[derive(ConstCapacity)]
+struct Buffers([u8; 4], [u8; 8]);
+
+#[derive(Clear, Length, PushChar, PushStr, Truncate)]
+struct Text(String);
+
+#[derive(Length, RetainMap)]
+struct Scores(std::collections::BTreeMap<&'static str, u32>);
+
+fn main() {
+  let mut queue: Queue<i32> = WithCapacity::with_capacity(2);
+  queue.push(2).unwrap();
+  queue.push_front(1).unwrap();
+  queue.push_many([3, 4, 5]).unwrap();
+  queue.retain(|elem| *elem != 4);
+  queue.retain_mut(|elem| {
+    *elem *= 10;
+    true
+  });
+  queue.swap([0, 1]).unwrap();
+  assert_eq!(queue.get(0), Ok(&20));
+  *queue.get_mut(0).unwrap() += 1;
+  assert_eq!(queue.iter().copied().collect::<Vec<_>>(), [21, 10, 30, 50]);
+  assert_eq!(queue.pop_front(), Ok(21));
+  assert_eq!(queue.pop(), Ok(50));
+  assert_eq!(queue.length(), 2);
+  assert!(queue.label.is_empty());
+  assert!(<Queue<i32> as TryWithCapacity>::try_with_capacity(usize::MAX).is_err());
+
+  let mut pair = Pair(vec![1, 2], vec![3]);
+  assert_eq!(pair.length(), 3);
+  pair.clear();
+  assert_eq!(pair.length(), 0);
+
+  let buffers = Buffers([0; 4], [0; 8]);
+  assert_eq!(buffers.0.len() + buffers.1.len(), Buffers::CAPACITY);
+  assert!(fits_in::<Buffers, [u8; 16]>());
+
+  let mut text = Text(String::new());
+  text.push_str("ab").unwrap();
+  text.push_char('é').unwrap();
+  assert_eq!(text.length(), 4);
+  assert_eq!(text.truncate(3), Err(CharBoundaryError { index: 3 }));
+  text.clear();
+  assert_eq!(text.0, "");
+
+  let mut scores = Scores([("a", 1), ("b", 2)].iter().copied().collect());
+  scores.retain_map(|_, score| {
+    *score *= 10;
+    *score > 10
+  });
+  assert_eq!(scores.length(), 1);
+  assert_eq!(scores.0.get("b"), Some(&20));
+}
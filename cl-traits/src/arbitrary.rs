@@ -0,0 +1,64 @@
+//! Integration with the `arbitrary` crate for storages and operations that are generic over the
+//! traits of this crate.
+
+use crate::{CapacityUpperBound, Operation, Push, WithCapacity};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Storage filled with arbitrary elements. The number of elements never exceeds the
+/// [`CapacityUpperBound`] of `S`.
+///
+/// Suitable for `#[arbitrary(with = cl_traits::arbitrary::collection)]`.
+///
+/// ```rust
+/// let mut u = arbitrary::Unstructured::new(&[7; 64]);
+/// let structure: cl_traits::ArrayStorage<u8, 4>;
+/// structure = cl_traits::arbitrary::collection(&mut u).unwrap();
+/// assert_eq!(&structure[..], &[7, 7, 7, 7]);
+/// ```
+#[inline]
+pub fn collection<'any, S>(u: &mut Unstructured<'any>) -> Result<S>
+where
+  S: CapacityUpperBound + Push + WithCapacity<Input = usize>,
+  <S as Push>::Input: Arbitrary<'any>,
+{
+  let upper_bound = S::with_capacity(0).capacity_upper_bound();
+  let len = u.arbitrary_len::<<S as Push>::Input>()?.min(upper_bound);
+  let mut storage = S::with_capacity(len);
+  for _ in 0..len {
+    let _ = storage.push(u.arbitrary()?);
+  }
+  Ok(storage)
+}
+
+/// Indices are taken from a `u8` so that they can still reference stored elements.
+///
+/// ```rust
+/// let mut u = arbitrary::Unstructured::new(&[1, 2, 3, 4]);
+/// let ops: Vec<cl_traits::Operation<i32>> = arbitrary::Arbitrary::arbitrary(&mut u).unwrap();
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// for op in ops {
+///   op.apply(&mut structure);
+/// }
+/// assert!(structure.len() <= 5);
+/// ```
+impl<'any, T> Arbitrary<'any> for Operation<T>
+where
+  T: Arbitrary<'any>,
+{
+  #[inline]
+  fn arbitrary(u: &mut Unstructured<'any>) -> Result<Self> {
+    Ok(match u.choose_index(7)? {
+      0 => Self::Clear,
+      1 => Self::Insert(index(u)?, u.arbitrary()?),
+      2 => Self::Push(u.arbitrary()?),
+      3 => Self::Remove(index(u)?),
+      4 => Self::Retain(u.arbitrary()?),
+      5 => Self::Swap([index(u)?, index(u)?]),
+      _ => Self::Truncate(index(u)?),
+    })
+  }
+}
+
+fn index(u: &mut Unstructured<'_>) -> Result<usize> {
+  Ok(u.arbitrary::<u8>()?.into())
+}
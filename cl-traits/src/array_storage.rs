@@ -0,0 +1,354 @@
+use crate::create_array;
+use core::{
+  fmt,
+  mem::{self, ManuallyDrop, MaybeUninit},
+  ops::{Deref, DerefMut},
+  ptr, slice,
+};
+
+/// Fixed-capacity vector that stores up to `N` elements in an inline array.
+///
+/// Only the first `len` slots are initialized, so unlike `[T; N]`, elements don't need a
+/// `Default` value.
+///
+/// * Example
+///
+/// ```rust
+/// use cl_traits::{ArrayStorage, Push, WithCapacity};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Handle(u8);
+///
+/// let mut structure: ArrayStorage<Handle, 2> = WithCapacity::with_capacity(2);
+/// structure.push(Handle(1)).unwrap();
+/// structure.push(Handle(2)).unwrap();
+/// assert_eq!(structure.into_array().ok(), Some([Handle(1), Handle(2)]));
+/// ```
+pub struct ArrayStorage<T, const N: usize> {
+  data: [MaybeUninit<T>; N],
+  len: usize,
+}
+
+impl<T, const N: usize> ArrayStorage<T, N> {
+  /// Creates an empty instance.
+  #[inline]
+  pub fn new() -> Self {
+    Self { data: create_array(|_| MaybeUninit::uninit()), len: 0 }
+  }
+
+  /// Creates a full instance where each element `T` is returned by the `cb` call.
+  ///
+  /// ```rust
+  /// let structure = cl_traits::ArrayStorage::<usize, 3>::from_fn(|idx| idx * 2);
+  /// assert_eq!(&structure[..], &[0, 2, 4]);
+  /// ```
+  #[inline]
+  pub fn from_fn<F>(mut cb: F) -> Self
+  where
+    F: FnMut(usize) -> T,
+  {
+    let mut this = Self::new();
+    for (idx, slot) in this.data.iter_mut().enumerate() {
+      *slot = MaybeUninit::new(cb(idx));
+      this.len = idx.wrapping_add(1);
+    }
+    this
+  }
+
+  /// Extracts a slice containing all initialized elements.
+  #[inline]
+  pub fn as_slice(&self) -> &[T] {
+    #[allow(
+      // The first `len` elements are always initialized
+      unsafe_code
+    )]
+    unsafe {
+      slice::from_raw_parts(self.data.as_ptr().cast(), self.len)
+    }
+  }
+
+  /// Extracts a mutable slice containing all initialized elements.
+  #[inline]
+  pub fn as_mut_slice(&mut self) -> &mut [T] {
+    #[allow(
+      // The first `len` elements are always initialized
+      unsafe_code
+    )]
+    unsafe {
+      slice::from_raw_parts_mut(self.data.as_mut_ptr().cast(), self.len)
+    }
+  }
+
+  /// Number of elements the instance is able to store, which is always `N`.
+  #[inline]
+  pub const fn capacity(&self) -> usize {
+    N
+  }
+
+  /// Drops all elements.
+  #[inline]
+  pub fn clear(&mut self) {
+    self.truncate(0);
+  }
+
+  /// Inserts `elem` at position `idx`, shifting all elements after it to the right.
+  ///
+  /// # Panics
+  ///
+  /// If `idx` is greater than the length or if all slots are occupied.
+  #[inline]
+  pub fn insert(&mut self, idx: usize, elem: T) {
+    let len = self.len;
+    assert!(idx <= len, "insertion index (is {}) should be <= len (is {})", idx, len);
+    assert!(len < N, "the capacity of {} elements is exhausted", N);
+    #[allow(
+      // `idx` is within the initialized part and there is at least one free slot after it
+      unsafe_code
+    )]
+    unsafe {
+      let ptr = self.data.as_mut_ptr().cast::<T>().add(idx);
+      ptr::copy(ptr, ptr.add(1), len.wrapping_sub(idx));
+      ptr::write(ptr, elem);
+    }
+    self.len = len.wrapping_add(1);
+  }
+
+  /// Converts a full instance into an array, otherwise gives back the instance.
+  #[inline]
+  pub fn into_array(self) -> Result<[T; N], Self> {
+    if self.len < N {
+      return Err(self);
+    }
+    let this = ManuallyDrop::new(self);
+    #[allow(
+      // All `N` elements are initialized and `this` won't drop them
+      unsafe_code
+    )]
+    unsafe {
+      Ok(ptr::read(this.data.as_ptr().cast()))
+    }
+  }
+
+  /// If no elements are stored.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// If all `N` slots are occupied.
+  #[inline]
+  pub fn is_full(&self) -> bool {
+    self.len >= N
+  }
+
+  /// Number of stored elements.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Number of elements that can still be pushed.
+  #[inline]
+  pub fn remaining_capacity(&self) -> usize {
+    N.wrapping_sub(self.len)
+  }
+
+  /// Removes and returns the element at position `idx`, shifting all elements after it to the left.
+  ///
+  /// # Panics
+  ///
+  /// If `idx` is greater than or equal to the length.
+  #[inline]
+  pub fn remove(&mut self, idx: usize) -> T {
+    let len = self.len;
+    assert!(idx < len, "removal index (is {}) should be < len (is {})", idx, len);
+    #[allow(
+      // `idx` is within the initialized part and the gap is immediately closed
+      unsafe_code
+    )]
+    let elem = unsafe {
+      let ptr = self.data.as_mut_ptr().cast::<T>().add(idx);
+      let elem = ptr::read(ptr);
+      ptr::copy(ptr.add(1), ptr, len.wrapping_sub(idx).wrapping_sub(1));
+      elem
+    };
+    self.len = len.wrapping_sub(1);
+    elem
+  }
+
+  /// Retains only the elements specified by the `cb` predicate, passing a mutable reference to
+  /// each element.
+  ///
+  /// If `cb` panics, the remaining elements are leaked instead of dropped.
+  #[inline]
+  pub fn retain_mut<F>(&mut self, mut cb: F)
+  where
+    F: FnMut(&mut T) -> bool,
+  {
+    let len = self.len;
+    self.len = 0;
+    let mut kept: usize = 0;
+    let ptr = self.data.as_mut_ptr().cast::<T>();
+    for idx in 0..len {
+      #[allow(
+        // Every `idx` is initialized and `kept <= idx` only points to moved-out or the same slots
+        unsafe_code
+      )]
+      unsafe {
+        let elem = ptr.add(idx);
+        if cb(&mut *elem) {
+          if kept != idx {
+            ptr::copy_nonoverlapping(elem, ptr.add(kept), 1);
+          }
+          kept = kept.wrapping_add(1);
+        } else {
+          ptr::drop_in_place(elem);
+        }
+      }
+    }
+    self.len = kept;
+  }
+
+  /// Removes and returns the last element, if any.
+  #[inline]
+  pub fn pop(&mut self) -> Option<T> {
+    let new_len = self.len.checked_sub(1)?;
+    let slot = mem::replace(self.data.get_mut(new_len)?, MaybeUninit::uninit());
+    self.len = new_len;
+    #[allow(
+      // Slots below the old `len` are initialized
+      unsafe_code
+    )]
+    unsafe {
+      Some(slot.assume_init())
+    }
+  }
+
+  /// Appends `elem` or gives it back if all slots are occupied.
+  #[inline]
+  pub fn try_push(&mut self, elem: T) -> Result<(), T> {
+    match self.data.get_mut(self.len) {
+      None => Err(elem),
+      Some(slot) => {
+        *slot = MaybeUninit::new(elem);
+        self.len = self.len.wrapping_add(1);
+        Ok(())
+      }
+    }
+  }
+
+  /// Drops all elements after the first `len` ones.
+  #[inline]
+  pub fn truncate(&mut self, len: usize) {
+    if len >= self.len {
+      return;
+    }
+    let tail_len = self.len.wrapping_sub(len);
+    self.len = len;
+    #[allow(
+      // Slots in `len..old_len` are initialized and are no longer reachable
+      unsafe_code
+    )]
+    unsafe {
+      let tail =
+        ptr::slice_from_raw_parts_mut(self.data.as_mut_ptr().add(len).cast::<T>(), tail_len);
+      ptr::drop_in_place(tail);
+    }
+  }
+}
+
+impl<T, const N: usize> AsMut<[T]> for ArrayStorage<T, N> {
+  #[inline]
+  fn as_mut(&mut self) -> &mut [T] {
+    self.as_mut_slice()
+  }
+}
+
+impl<T, const N: usize> AsRef<[T]> for ArrayStorage<T, N> {
+  #[inline]
+  fn as_ref(&self) -> &[T] {
+    self.as_slice()
+  }
+}
+
+impl<T, const N: usize> Clone for ArrayStorage<T, N>
+where
+  T: Clone,
+{
+  #[inline]
+  fn clone(&self) -> Self {
+    let mut this = Self::new();
+    for (slot, elem) in this.data.iter_mut().zip(self.as_slice()) {
+      *slot = MaybeUninit::new(elem.clone());
+      this.len = this.len.wrapping_add(1);
+    }
+    this
+  }
+}
+
+impl<T, const N: usize> fmt::Debug for ArrayStorage<T, N>
+where
+  T: fmt::Debug,
+{
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_list().entries(self.as_slice()).finish()
+  }
+}
+
+impl<T, const N: usize> Default for ArrayStorage<T, N> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T, const N: usize> Deref for ArrayStorage<T, N> {
+  type Target = [T];
+
+  #[inline]
+  fn deref(&self) -> &Self::Target {
+    self.as_slice()
+  }
+}
+
+impl<T, const N: usize> DerefMut for ArrayStorage<T, N> {
+  #[inline]
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    self.as_mut_slice()
+  }
+}
+
+impl<T, const N: usize> Drop for ArrayStorage<T, N> {
+  #[inline]
+  fn drop(&mut self) {
+    self.truncate(0);
+  }
+}
+
+impl<T, const N: usize> Eq for ArrayStorage<T, N> where T: Eq {}
+
+/// # Panics
+///
+/// If `iter` yields more elements than the remaining capacity.
+impl<T, const N: usize> Extend<T> for ArrayStorage<T, N> {
+  #[inline]
+  fn extend<I>(&mut self, iter: I)
+  where
+    I: IntoIterator<Item = T>,
+  {
+    for elem in iter {
+      assert!(self.try_push(elem).is_ok(), "the capacity of {} elements is exhausted", N);
+    }
+  }
+}
+
+impl<T, const N: usize> PartialEq for ArrayStorage<T, N>
+where
+  T: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.as_slice() == other.as_slice()
+  }
+}
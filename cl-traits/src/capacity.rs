@@ -1,5 +1,15 @@
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, BTreeSet, VecDeque},
+  rc::Rc,
+  string::String,
+  vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// See [`capacity`](Capacity::capacity) for more information.
 pub trait Capacity {
@@ -8,6 +18,127 @@ pub trait Capacity {
   fn capacity(&self) -> usize;
 }
 
+/// ```rust
+/// let structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::Capacity::capacity(&&structure), 3);
+/// ```
+impl<T> Capacity for &'_ T
+where
+  T: Capacity + ?Sized,
+{
+  #[inline]
+  fn capacity(&self) -> usize {
+    (**self).capacity()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::Capacity::capacity(&&mut structure), 3);
+/// ```
+impl<T> Capacity for &'_ mut T
+where
+  T: Capacity + ?Sized,
+{
+  #[inline]
+  fn capacity(&self) -> usize {
+    (**self).capacity()
+  }
+}
+
+/// ```rust
+/// let structure = Box::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 5);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Capacity for Box<T>
+where
+  T: Capacity + ?Sized,
+{
+  #[inline]
+  fn capacity(&self) -> usize {
+    (**self).capacity()
+  }
+}
+
+/// ```rust
+/// let structure = std::rc::Rc::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 5);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Capacity for Rc<T>
+where
+  T: Capacity + ?Sized,
+{
+  #[inline]
+  fn capacity(&self) -> usize {
+    (**self).capacity()
+  }
+}
+
+/// ```rust
+/// let structure = std::sync::Arc::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 5);
+/// ```
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> Capacity for Arc<T>
+where
+  T: Capacity + ?Sized,
+{
+  #[inline]
+  fn capacity(&self) -> usize {
+    (**self).capacity()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::b_tree_map();
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> Capacity for BTreeMap<K, V> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::b_tree_set();
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<V> Capacity for BTreeSet<V> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hash_map();
+/// assert!(cl_traits::Capacity::capacity(&structure) >= 3);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> Capacity for HashMap<K, V> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hash_set();
+/// assert!(cl_traits::Capacity::capacity(&structure) >= 3);
+/// ```
+#[cfg(feature = "std")]
+impl<V> Capacity for HashSet<V> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
 /// ```rust
 /// assert_eq!(cl_traits::Capacity::capacity(&Some(0)), 1);
 /// ```
@@ -31,9 +162,9 @@ impl<T, const N: usize> Capacity for [T; N] {
 
 /// ```rust
 /// let structure = cl_traits::doc_tests::slice();
-/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// assert_eq!(cl_traits::Capacity::capacity(structure), 3);
 /// ```
-impl<T> Capacity for &'_ [T] {
+impl<T> Capacity for [T] {
   #[inline]
   fn capacity(&self) -> usize {
     self.len()
@@ -41,12 +172,25 @@ impl<T> Capacity for &'_ [T] {
 }
 
 /// ```rust
-/// assert_eq!(cl_traits::Length::length(&&mut [1, 2, 3][..]), 3);
+/// let structure = cl_traits::doc_tests::array_storage();
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 5);
 /// ```
-impl<T> Capacity for &'_ mut [T] {
+impl<T, const N: usize> Capacity for crate::ArrayStorage<T, N> {
   #[inline]
   fn capacity(&self) -> usize {
-    self.len()
+    N
+  }
+}
+
+/// ```rust
+/// let structure = String::with_capacity(2);
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl Capacity for String {
+  #[inline]
+  fn capacity(&self) -> usize {
+    self.capacity()
   }
 }
 
@@ -62,6 +206,30 @@ impl<T> Capacity for Vec<T> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// assert!(cl_traits::Capacity::capacity(&structure) >= 5);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Capacity for VecDeque<T> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::array_string();
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 5);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<const CAP: usize> Capacity for arrayvec::ArrayString<CAP> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    CAP
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::array_vec();
 /// assert_eq!(cl_traits::Capacity::capacity(&structure), 5);
@@ -74,6 +242,102 @@ impl<T, const N: usize> Capacity for arrayvec::ArrayVec<T, N> {
   }
 }
 
+/// ```rust
+/// let structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// assert!(cl_traits::Capacity::capacity(&structure) >= 3);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> Capacity for hashbrown::HashMap<K, V, S> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hashbrown_hash_set();
+/// assert!(cl_traits::Capacity::capacity(&structure) >= 3);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> Capacity for hashbrown::HashSet<V, S> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_deque();
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 5);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Capacity for heapless::Deque<T, N> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    N
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_index_map();
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 8);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> Capacity for heapless::IndexMap<K, V, S, N> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    N
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_string();
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 10);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> Capacity for heapless::String<N> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    N
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_vec();
+/// assert_eq!(cl_traits::Capacity::capacity(&structure), 5);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Capacity for heapless::Vec<T, N> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    N
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::index_map();
+/// assert!(cl_traits::Capacity::capacity(&structure) >= 3);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Capacity for indexmap::IndexMap<K, V, S> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::index_set();
+/// assert!(cl_traits::Capacity::capacity(&structure) >= 3);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Capacity for indexmap::IndexSet<V, S> {
+  #[inline]
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::small_vec();
 /// assert_eq!(cl_traits::Capacity::capacity(&structure), 5);
@@ -1,6 +1,15 @@
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, BTreeSet, VecDeque},
+  rc::Rc,
+  vec::Vec,
+};
 use core::mem;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// See [`capacity_upper_bound`](CapacityUpperBound::capacity_upper_bound) for more information.
 pub trait CapacityUpperBound {
@@ -8,6 +17,127 @@ pub trait CapacityUpperBound {
   fn capacity_upper_bound(&self) -> usize;
 }
 
+/// ```rust
+/// let structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&&structure), 3);
+/// ```
+impl<T> CapacityUpperBound for &'_ T
+where
+  T: CapacityUpperBound + ?Sized,
+{
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    (**self).capacity_upper_bound()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&&mut structure), 3);
+/// ```
+impl<T> CapacityUpperBound for &'_ mut T
+where
+  T: CapacityUpperBound + ?Sized,
+{
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    (**self).capacity_upper_bound()
+  }
+}
+
+/// ```rust
+/// let structure = Box::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 2305843009213693951);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> CapacityUpperBound for Box<T>
+where
+  T: CapacityUpperBound + ?Sized,
+{
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    (**self).capacity_upper_bound()
+  }
+}
+
+/// ```rust
+/// let structure = std::rc::Rc::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 2305843009213693951);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> CapacityUpperBound for Rc<T>
+where
+  T: CapacityUpperBound + ?Sized,
+{
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    (**self).capacity_upper_bound()
+  }
+}
+
+/// ```rust
+/// let structure = std::sync::Arc::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 2305843009213693951);
+/// ```
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> CapacityUpperBound for Arc<T>
+where
+  T: CapacityUpperBound + ?Sized,
+{
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    (**self).capacity_upper_bound()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::b_tree_map();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 1152921504606846975);
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> CapacityUpperBound for BTreeMap<K, V> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    capacity_upper_bound_for_heap::<(K, V)>()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::b_tree_set();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 2305843009213693951);
+/// ```
+#[cfg(feature = "alloc")]
+impl<V> CapacityUpperBound for BTreeSet<V> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    capacity_upper_bound_for_heap::<V>()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hash_map();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 1152921504606846975);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> CapacityUpperBound for HashMap<K, V> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    capacity_upper_bound_for_heap::<(K, V)>()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hash_set();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 2305843009213693951);
+/// ```
+#[cfg(feature = "std")]
+impl<V> CapacityUpperBound for HashSet<V> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    capacity_upper_bound_for_heap::<V>()
+  }
+}
+
 /// ```rust
 /// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&Some(0)), 1);
 /// ```
@@ -31,9 +161,9 @@ impl<T, const N: usize> CapacityUpperBound for [T; N] {
 
 /// ```rust
 /// let structure = cl_traits::doc_tests::slice();
-/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 3);
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(structure), 3);
 /// ```
-impl<T> CapacityUpperBound for &'_ [T] {
+impl<T> CapacityUpperBound for [T] {
   #[inline]
   fn capacity_upper_bound(&self) -> usize {
     self.len()
@@ -41,18 +171,20 @@ impl<T> CapacityUpperBound for &'_ [T] {
 }
 
 /// ```rust
-/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&&mut [1, 2, 3][..]), 3);
+/// let structure = cl_traits::doc_tests::array_storage();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 5);
 /// ```
-impl<T> CapacityUpperBound for &'_ mut [T] {
+impl<T, const N: usize> CapacityUpperBound for crate::ArrayStorage<T, N> {
   #[inline]
   fn capacity_upper_bound(&self) -> usize {
-    self.len()
+    N
   }
 }
 
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::vec();
 /// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 2305843009213693951);
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&Vec::<()>::new()), usize::MAX);
 /// ```
 #[cfg(feature = "alloc")]
 impl<T> CapacityUpperBound for Vec<T> {
@@ -62,6 +194,18 @@ impl<T> CapacityUpperBound for Vec<T> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 2305843009213693951);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> CapacityUpperBound for VecDeque<T> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    capacity_upper_bound_for_heap::<T>()
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::array_vec();
 /// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 5);
@@ -74,6 +218,102 @@ impl<T, const N: usize> CapacityUpperBound for arrayvec::ArrayVec<T, N> {
   }
 }
 
+/// ```rust
+/// let structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// assert!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure) > 3);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> CapacityUpperBound for hashbrown::HashMap<K, V, S> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    capacity_upper_bound_for_heap::<(K, V)>()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hashbrown_hash_set();
+/// assert!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure) > 3);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> CapacityUpperBound for hashbrown::HashSet<V, S> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    capacity_upper_bound_for_heap::<V>()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_deque();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 5);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> CapacityUpperBound for heapless::Deque<T, N> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    N
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_index_map();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 8);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> CapacityUpperBound for heapless::IndexMap<K, V, S, N> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    N
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_string();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 10);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> CapacityUpperBound for heapless::String<N> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    N
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_vec();
+/// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 5);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> CapacityUpperBound for heapless::Vec<T, N> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    N
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::index_map();
+/// assert!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure) > 3);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> CapacityUpperBound for indexmap::IndexMap<K, V, S> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    capacity_upper_bound_for_heap::<(K, V)>()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::index_set();
+/// assert!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure) > 3);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> CapacityUpperBound for indexmap::IndexSet<V, S> {
+  #[inline]
+  fn capacity_upper_bound(&self) -> usize {
+    capacity_upper_bound_for_heap::<V>()
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::small_vec();
 /// assert_eq!(cl_traits::CapacityUpperBound::capacity_upper_bound(&structure), 2305843009213693951);
@@ -143,7 +383,10 @@ where
 fn capacity_upper_bound_for_heap<T>() -> usize {
   let size_of_t = mem::size_of::<T>();
   let isize_max_usize = isize::MAX as usize;
-  if size_of_t > isize_max_usize {
+  if size_of_t == 0 {
+    // Zero-sized types never allocate
+    usize::MAX
+  } else if size_of_t > isize_max_usize {
     0
   } else {
     isize_max_usize.wrapping_div(size_of_t)
@@ -1,5 +1,12 @@
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+  string::String,
+  vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// See [`clear`](Clear::clear) for more information.
 pub trait Clear {
@@ -7,6 +14,89 @@ pub trait Clear {
   fn clear(&mut self);
 }
 
+/// ```rust
+/// let mut structure = Some(1);
+/// cl_traits::Clear::clear(&mut &mut structure);
+/// assert_eq!(structure, None);
+/// ```
+impl<T> Clear for &'_ mut T
+where
+  T: Clear + ?Sized,
+{
+  #[inline]
+  fn clear(&mut self) {
+    (**self).clear()
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Clear for Box<T>
+where
+  T: Clear + ?Sized,
+{
+  #[inline]
+  fn clear(&mut self) {
+    (**self).clear()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::b_tree_map();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> Clear for BTreeMap<K, V> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::b_tree_set();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<V> Clear for BTreeSet<V> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_map();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> Clear for HashMap<K, V> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_set();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "std")]
+impl<V> Clear for HashSet<V> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
 /// ```rust
 /// let mut opt = Some(0);
 /// cl_traits::Clear::clear(&mut opt);
@@ -19,6 +109,31 @@ impl<T> Clear for Option<T> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+impl<T, const N: usize> Clear for crate::ArrayStorage<T, N> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::string();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl Clear for String {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::vec();
 /// cl_traits::Clear::clear(&mut structure);
@@ -32,6 +147,45 @@ impl<T> Clear for Vec<T> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::linked_list();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Clear for LinkedList<T> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Clear for VecDeque<T> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_string();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<const CAP: usize> Clear for arrayvec::ArrayString<CAP> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::array_vec();
 /// cl_traits::Clear::clear(&mut structure);
@@ -45,6 +199,110 @@ impl<T, const N: usize> Clear for arrayvec::ArrayVec<T, N> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> Clear for hashbrown::HashMap<K, V, S> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_set();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> Clear for hashbrown::HashSet<V, S> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_deque();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Clear for heapless::Deque<T, N> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_index_map();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> Clear for heapless::IndexMap<K, V, S, N> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_string();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> Clear for heapless::String<N> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_vec();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Clear for heapless::Vec<T, N> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Clear for indexmap::IndexMap<K, V, S> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_set();
+/// cl_traits::Clear::clear(&mut structure);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Clear for indexmap::IndexSet<V, S> {
+  #[inline]
+  fn clear(&mut self) {
+    self.clear();
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::small_vec();
 /// cl_traits::Clear::clear(&mut structure);
@@ -0,0 +1,212 @@
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc};
+use core::marker::PhantomData;
+
+/// Capacity known at compile time.
+///
+/// Only implemented by storages that can never hold more than `CAPACITY` elements.
+pub trait ConstCapacity {
+  /// The number of elements the implementation is able to store.
+  const CAPACITY: usize;
+}
+
+/// ```rust
+/// assert_eq!(<&[i32; 3] as cl_traits::ConstCapacity>::CAPACITY, 3);
+/// ```
+impl<T> ConstCapacity for &'_ T
+where
+  T: ConstCapacity + ?Sized,
+{
+  const CAPACITY: usize = T::CAPACITY;
+}
+
+/// ```rust
+/// assert_eq!(<&mut [i32; 3] as cl_traits::ConstCapacity>::CAPACITY, 3);
+/// ```
+impl<T> ConstCapacity for &'_ mut T
+where
+  T: ConstCapacity + ?Sized,
+{
+  const CAPACITY: usize = T::CAPACITY;
+}
+
+/// ```rust
+/// assert_eq!(<Box<[i32; 3]> as cl_traits::ConstCapacity>::CAPACITY, 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> ConstCapacity for Box<T>
+where
+  T: ConstCapacity + ?Sized,
+{
+  const CAPACITY: usize = T::CAPACITY;
+}
+
+/// ```rust
+/// assert_eq!(<std::rc::Rc<[i32; 3]> as cl_traits::ConstCapacity>::CAPACITY, 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> ConstCapacity for Rc<T>
+where
+  T: ConstCapacity + ?Sized,
+{
+  const CAPACITY: usize = T::CAPACITY;
+}
+
+/// ```rust
+/// assert_eq!(<std::sync::Arc<[i32; 3]> as cl_traits::ConstCapacity>::CAPACITY, 3);
+/// ```
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> ConstCapacity for Arc<T>
+where
+  T: ConstCapacity + ?Sized,
+{
+  const CAPACITY: usize = T::CAPACITY;
+}
+
+/// ```rust
+/// assert_eq!(<Option<i32> as cl_traits::ConstCapacity>::CAPACITY, 1);
+/// ```
+impl<T> ConstCapacity for Option<T> {
+  const CAPACITY: usize = 1;
+}
+
+/// ```rust
+/// assert_eq!(<[i32; 3] as cl_traits::ConstCapacity>::CAPACITY, 3);
+/// ```
+impl<T, const N: usize> ConstCapacity for [T; N] {
+  const CAPACITY: usize = N;
+}
+
+/// ```rust
+/// assert_eq!(<cl_traits::ArrayStorage<i32, 5> as cl_traits::ConstCapacity>::CAPACITY, 5);
+/// ```
+impl<T, const N: usize> ConstCapacity for crate::ArrayStorage<T, N> {
+  const CAPACITY: usize = N;
+}
+
+/// Capacity in bytes
+///
+/// ```rust
+/// assert_eq!(<arrayvec::ArrayString<5> as cl_traits::ConstCapacity>::CAPACITY, 5);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<const N: usize> ConstCapacity for arrayvec::ArrayString<N> {
+  const CAPACITY: usize = N;
+}
+
+/// ```rust
+/// assert_eq!(<arrayvec::ArrayVec<i32, 5> as cl_traits::ConstCapacity>::CAPACITY, 5);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> ConstCapacity for arrayvec::ArrayVec<T, N> {
+  const CAPACITY: usize = N;
+}
+
+/// ```rust
+/// assert_eq!(<heapless::Deque<i32, 5> as cl_traits::ConstCapacity>::CAPACITY, 5);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> ConstCapacity for heapless::Deque<T, N> {
+  const CAPACITY: usize = N;
+}
+
+/// ```rust
+/// assert_eq!(<heapless::FnvIndexMap<i32, i32, 8> as cl_traits::ConstCapacity>::CAPACITY, 8);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> ConstCapacity for heapless::IndexMap<K, V, S, N> {
+  const CAPACITY: usize = N;
+}
+
+/// Capacity in bytes
+///
+/// ```rust
+/// assert_eq!(<heapless::String<5> as cl_traits::ConstCapacity>::CAPACITY, 5);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> ConstCapacity for heapless::String<N> {
+  const CAPACITY: usize = N;
+}
+
+/// ```rust
+/// assert_eq!(<heapless::Vec<i32, 5> as cl_traits::ConstCapacity>::CAPACITY, 5);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> ConstCapacity for heapless::Vec<T, N> {
+  const CAPACITY: usize = N;
+}
+
+/// ```rust
+/// assert_eq!(<staticvec::StaticVec<i32, 5> as cl_traits::ConstCapacity>::CAPACITY, 5);
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> ConstCapacity for staticvec::StaticVec<T, N> {
+  const CAPACITY: usize = N;
+}
+
+/// ```rust
+/// assert_eq!(<tinyvec::ArrayVec<[i32; 5]> as cl_traits::ConstCapacity>::CAPACITY, 5);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> ConstCapacity for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  const CAPACITY: usize = A::CAPACITY;
+}
+
+/// Returns `true` if all elements of a full `A` can be stored in `B`.
+///
+/// * Example
+///
+/// ```rust
+/// use cl_traits::fits_in;
+/// const FITS: bool = fits_in::<[i32; 2], [i32; 3]>();
+/// assert!(FITS);
+/// assert!(!fits_in::<[i32; 4], [i32; 3]>());
+/// ```
+#[inline]
+pub const fn fits_in<A, B>() -> bool
+where
+  A: ConstCapacity + ?Sized,
+  B: ConstCapacity + ?Sized,
+{
+  A::CAPACITY <= B::CAPACITY
+}
+
+/// Fails compilation if the elements of a full `A` can't be stored in `B`.
+///
+/// * Example
+///
+/// ```rust
+/// const _: () = cl_traits::assert_fits_in::<[i32; 2], [i32; 3]>();
+/// ```
+///
+/// ```compile_fail
+/// const _: () = cl_traits::assert_fits_in::<[i32; 4], [i32; 3]>();
+/// ```
+#[inline]
+pub const fn assert_fits_in<A, B>()
+where
+  A: ConstCapacity + ?Sized,
+  B: ConstCapacity + ?Sized,
+{
+  FitsIn::<A, B>::ASSERTION
+}
+
+struct FitsIn<A, B>(PhantomData<A>, PhantomData<B>)
+where
+  A: ?Sized,
+  B: ?Sized;
+
+impl<A, B> FitsIn<A, B>
+where
+  A: ConstCapacity + ?Sized,
+  B: ConstCapacity + ?Sized,
+{
+  const ASSERTION: () =
+    assert!(fits_in::<A, B>(), "the capacity of `A` exceeds the capacity of `B`");
+}
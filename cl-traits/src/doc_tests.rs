@@ -2,7 +2,8 @@
 
 #[cfg(feature = "alloc")]
 use alloc::{
-  collections::{BTreeMap, BTreeSet},
+  collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+  string::String,
   vec::Vec,
 };
 #[cfg(feature = "std")]
@@ -14,6 +15,25 @@ pub fn array() -> [i32; 3] {
   [1, 2, 3]
 }
 
+/// `ArrayStorage` with three elements
+#[inline]
+pub fn array_storage() -> crate::ArrayStorage<i32, 5> {
+  let mut structure = crate::ArrayStorage::new();
+  for elem in [1, 2, 3] {
+    let _ = structure.try_push(elem);
+  }
+  structure
+}
+
+/// `ArrayString` with three characters
+#[cfg(feature = "with-arrayvec")]
+#[inline]
+pub fn array_string() -> arrayvec::ArrayString<5> {
+  let mut string = arrayvec::ArrayString::new();
+  string.push_str("abc");
+  string
+}
+
 /// `ArrayVec` with three elements
 #[cfg(feature = "with-arrayvec")]
 #[inline]
@@ -51,12 +71,121 @@ pub fn hash_set() -> HashSet<i32> {
   [1, 2, 3].iter().copied().collect()
 }
 
+/// `hashbrown::HashMap` with three elements
+#[cfg(feature = "with-hashbrown")]
+#[inline]
+pub fn hashbrown_hash_map() -> hashbrown::HashMap<i32, i32> {
+  [(0, 1), (1, 2), (2, 3)].iter().copied().collect()
+}
+
+/// `hashbrown::HashSet` with three elements
+#[cfg(feature = "with-hashbrown")]
+#[inline]
+pub fn hashbrown_hash_set() -> hashbrown::HashSet<i32> {
+  [1, 2, 3].iter().copied().collect()
+}
+
+/// `heapless::Deque` with three elements
+#[cfg(feature = "with-heapless")]
+#[inline]
+pub fn heapless_deque() -> heapless::Deque<i32, 5> {
+  let mut deque = heapless::Deque::new();
+  for elem in [1, 2, 3] {
+    let _ = deque.push_back(elem);
+  }
+  deque
+}
+
+/// `heapless::IndexMap` with three elements
+#[cfg(feature = "with-heapless")]
+#[inline]
+pub fn heapless_index_map() -> heapless::FnvIndexMap<i32, i32, 8> {
+  [(0, 1), (1, 2), (2, 3)].iter().copied().collect()
+}
+
+/// `heapless::String` with three characters
+#[cfg(feature = "with-heapless")]
+#[inline]
+pub fn heapless_string() -> heapless::String<10> {
+  "abc".chars().collect()
+}
+
+/// `heapless::Vec` with three elements
+#[cfg(feature = "with-heapless")]
+#[inline]
+pub fn heapless_vec() -> heapless::Vec<i32, 5> {
+  [1, 2, 3].iter().copied().collect()
+}
+
+// Test-only hasher that lets the `indexmap` fixtures be built without `std`, not meant to be
+// used elsewhere.
+#[cfg(feature = "with-indexmap")]
+#[doc(hidden)]
+pub type FnvBuildHasher = core::hash::BuildHasherDefault<FnvHasher>;
+
+// Test-only FNV-1a hasher, see `FnvBuildHasher`.
+#[cfg(feature = "with-indexmap")]
+#[derive(Debug)]
+#[doc(hidden)]
+pub struct FnvHasher(u64);
+
+#[cfg(feature = "with-indexmap")]
+impl Default for FnvHasher {
+  #[inline]
+  fn default() -> Self {
+    Self(0xcbf2_9ce4_8422_2325)
+  }
+}
+
+#[cfg(feature = "with-indexmap")]
+impl core::hash::Hasher for FnvHasher {
+  #[inline]
+  fn finish(&self) -> u64 {
+    self.0
+  }
+
+  #[inline]
+  fn write(&mut self, bytes: &[u8]) {
+    for byte in bytes {
+      self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+    }
+  }
+}
+
+/// `IndexMap` with three elements
+#[cfg(feature = "with-indexmap")]
+#[inline]
+pub fn index_map() -> indexmap::IndexMap<i32, i32, FnvBuildHasher> {
+  [(0, 1), (1, 2), (2, 3)].iter().copied().collect()
+}
+
+/// `IndexSet` with three elements
+#[cfg(feature = "with-indexmap")]
+#[inline]
+pub fn index_set() -> indexmap::IndexSet<i32, FnvBuildHasher> {
+  [1, 2, 3].iter().copied().collect()
+}
+
+/// `LinkedList` with three elements
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn linked_list() -> LinkedList<i32> {
+  [1, 2, 3].iter().copied().collect()
+}
+
 /// Slice with three elements
 #[inline]
 pub fn slice() -> &'static [i32] {
   &[1, 2, 3]
 }
 
+/// `SmallString` with three characters
+#[cfg(feature = "with-smallstr")]
+#[inline]
+pub fn small_string() -> smallstr::SmallString<[u8; 4]> {
+  smallstr::SmallString::from_str("abc")
+}
+
 #[cfg(feature = "with-smallvec")]
 #[inline]
 /// `SmallVec` with three elements
@@ -75,6 +204,13 @@ pub fn static_vec() -> staticvec::StaticVec<i32, 5> {
   vec
 }
 
+/// `String` with three characters
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn string() -> String {
+  String::from("abc")
+}
+
 #[cfg(feature = "with-tinyvec")]
 #[inline]
 /// `TinyVec` with three elements
@@ -101,3 +237,12 @@ pub fn vec() -> Vec<i32> {
   vec.extend([1, 2, 3].iter().copied());
   vec
 }
+
+#[cfg(feature = "alloc")]
+#[inline]
+/// `VecDeque` with three elements
+pub fn vec_deque() -> VecDeque<i32> {
+  let mut vec = VecDeque::with_capacity(5);
+  vec.extend([1, 2, 3].iter().copied());
+  vec
+}
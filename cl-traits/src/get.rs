@@ -0,0 +1,531 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, VecDeque},
+  vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// See [`get`](Get::get) for more information.
+pub trait Get {
+  /// Error
+  type Error;
+  /// Input
+  type Input<'input>
+  where
+    Self: 'input;
+  /// Ok
+  type Ok<'ok>
+  where
+    Self: 'ok;
+
+  /// Gets a shared reference of an element referenced by `Input`.
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input;
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::Get::get(&&structure, 0), Ok(&1));
+/// ```
+impl<T> Get for &'_ T
+where
+  T: Get + ?Sized,
+{
+  type Error = T::Error;
+  type Input<'input>
+    = T::Input<'input>
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = T::Ok<'ok>
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    (**self).get(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::Get::get(&&mut structure, 0), Ok(&1));
+/// ```
+impl<T> Get for &'_ mut T
+where
+  T: Get + ?Sized,
+{
+  type Error = T::Error;
+  type Input<'input>
+    = T::Input<'input>
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = T::Ok<'ok>
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    (**self).get(input)
+  }
+}
+
+/// ```rust
+/// let structure = Box::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::Get::get(&structure, 0), Ok(&1));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Get for Box<T>
+where
+  T: Get + ?Sized,
+{
+  type Error = T::Error;
+  type Input<'input>
+    = T::Input<'input>
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = T::Ok<'ok>
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    (**self).get(input)
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::b_tree_map();
+/// assert_eq!(cl_traits::Get::get(&structure, &1), Ok(&2));
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> Get for BTreeMap<K, V>
+where
+  K: Ord,
+{
+  type Error = ();
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok V
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hash_map();
+/// assert_eq!(cl_traits::Get::get(&structure, &1), Ok(&2));
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> Get for HashMap<K, V>
+where
+  K: Eq + core::hash::Hash,
+{
+  type Error = ();
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok V
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let opt = Some(1);
+/// assert_eq!(cl_traits::Get::get(&opt, 0), Ok(&1));
+/// assert_eq!(cl_traits::Get::get(&opt, 1), Err(()));
+/// ```
+impl<T> Get for Option<T> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    if input == 0 {
+      self.as_ref().ok_or(())
+    } else {
+      Err(())
+    }
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::Get::get(&structure, 0), Ok(&1));
+/// ```
+impl<T, const N: usize> Get for [T; N] {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_slice().get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::slice();
+/// assert_eq!(cl_traits::Get::get(structure, 0), Ok(&1));
+/// ```
+impl<T> Get for [T] {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    <[T]>::get(self, input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::array_storage();
+/// assert_eq!(cl_traits::Get::get(&structure, 0), Ok(&1));
+/// ```
+impl<T, const N: usize> Get for crate::ArrayStorage<T, N> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_slice().get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::vec();
+/// assert_eq!(cl_traits::Get::get(&structure, 0), Ok(&1));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Get for Vec<T> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_slice().get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::vec_deque();
+/// assert_eq!(cl_traits::Get::get(&structure, 0), Ok(&1));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Get for VecDeque<T> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::array_vec();
+/// assert_eq!(cl_traits::Get::get(&structure, 0), Ok(&1));
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> Get for arrayvec::ArrayVec<T, N> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_slice().get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// assert_eq!(cl_traits::Get::get(&structure, &1), Ok(&2));
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> Get for hashbrown::HashMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = ();
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok V
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::index_map();
+/// assert_eq!(cl_traits::Get::get(&structure, 1), Ok((&1, &2)));
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Get for indexmap::IndexMap<K, V, S> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = (&'ok K, &'ok V)
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get_index(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::index_set();
+/// assert_eq!(cl_traits::Get::get(&structure, 1), Ok(&2));
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Get for indexmap::IndexSet<V, S> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok V
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get_index(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::small_vec();
+/// assert_eq!(cl_traits::Get::get(&structure, 0), Ok(&1));
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> Get for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok A::Item
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_slice().get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::static_vec();
+/// assert_eq!(cl_traits::Get::get(&structure, 0), Ok(&1));
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> Get for staticvec::StaticVec<T, N> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_slice().get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// assert_eq!(cl_traits::Get::get(&structure, 0), Ok(&1));
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> Get for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok A::Item
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_slice().get(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::tiny_vec();
+/// assert_eq!(cl_traits::Get::get(&structure, 0), Ok(&1));
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> Get for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok A::Item
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get<'input>(&self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_slice().get(input).ok_or(())
+  }
+}
@@ -0,0 +1,479 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, VecDeque},
+  vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// See [`get_mut`](GetMut::get_mut) for more information.
+pub trait GetMut {
+  /// Error
+  type Error;
+  /// Input
+  type Input<'input>
+  where
+    Self: 'input;
+  /// Ok
+  type Ok<'ok>
+  where
+    Self: 'ok;
+
+  /// Gets an exclusive reference of an element referenced by `Input`.
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input;
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut &mut structure, 0), Ok(&mut 1));
+/// ```
+impl<T> GetMut for &'_ mut T
+where
+  T: GetMut + ?Sized,
+{
+  type Error = T::Error;
+  type Input<'input>
+    = T::Input<'input>
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = T::Ok<'ok>
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    (**self).get_mut(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 0), Ok(&mut 1));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> GetMut for Box<T>
+where
+  T: GetMut + ?Sized,
+{
+  type Error = T::Error;
+  type Input<'input>
+    = T::Input<'input>
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = T::Ok<'ok>
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    (**self).get_mut(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::b_tree_map();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, &1), Ok(&mut 2));
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> GetMut for BTreeMap<K, V>
+where
+  K: Ord,
+{
+  type Error = ();
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut V
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_map();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, &1), Ok(&mut 2));
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> GetMut for HashMap<K, V>
+where
+  K: Eq + core::hash::Hash,
+{
+  type Error = ();
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut V
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut opt = Some(1);
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut opt, 0), Ok(&mut 1));
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut opt, 1), Err(()));
+/// ```
+impl<T> GetMut for Option<T> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    if input == 0 {
+      self.as_mut().ok_or(())
+    } else {
+      Err(())
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 0), Ok(&mut 1));
+/// ```
+impl<T, const N: usize> GetMut for [T; N] {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_mut_slice().get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut array = [1, 2, 3];
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut array[..], 0), Ok(&mut 1));
+/// ```
+impl<T> GetMut for [T] {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    <[T]>::get_mut(self, input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 0), Ok(&mut 1));
+/// ```
+impl<T, const N: usize> GetMut for crate::ArrayStorage<T, N> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_mut_slice().get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 0), Ok(&mut 1));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> GetMut for Vec<T> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_mut_slice().get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 0), Ok(&mut 1));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> GetMut for VecDeque<T> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 0), Ok(&mut 1));
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> GetMut for arrayvec::ArrayVec<T, N> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_mut_slice().get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, &1), Ok(&mut 2));
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> GetMut for hashbrown::HashMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = ();
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut V
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 1), Ok((&1, &mut 2)));
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> GetMut for indexmap::IndexMap<K, V, S> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = (&'ok K, &'ok mut V)
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.get_index_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 0), Ok(&mut 1));
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> GetMut for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut A::Item
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_mut_slice().get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 0), Ok(&mut 1));
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> GetMut for staticvec::StaticVec<T, N> {
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut T
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_mut_slice().get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 0), Ok(&mut 1));
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> GetMut for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut A::Item
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_mut_slice().get_mut(input).ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// assert_eq!(cl_traits::GetMut::get_mut(&mut structure, 0), Ok(&mut 1));
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> GetMut for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ();
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok<'ok>
+    = &'ok mut A::Item
+  where
+    Self: 'ok;
+
+  #[inline]
+  fn get_mut<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok<'_>, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.as_mut_slice().get_mut(input).ok_or(())
+  }
+}
@@ -1,8 +1,10 @@
 #[cfg(feature = "alloc")]
 use alloc::{
+  boxed::Box,
   collections::{BTreeMap, BTreeSet},
   vec::Vec,
 };
+use core::fmt;
 #[cfg(feature = "std")]
 use std::collections::{HashMap, HashSet};
 
@@ -13,13 +15,105 @@ use std::collections::{HashMap, HashSet};
 // Manages vectors that don't perform bound checks
 macro_rules! manage_vec {
   ($v:expr, $idx:expr, $elem:expr) => {{
-    if $idx > $v.len() {
-      return Err($elem);
+    let len = $v.len();
+    if $idx > len {
+      return Err(InsertError::new(
+        $elem,
+        InsertErrorReason::IndexOutOfBounds { index: $idx, len },
+      ));
     }
     Ok($v.insert($idx, $elem))
   }};
 }
 
+#[allow(
+  // For convenience because of selected features
+  unused
+)]
+// Manages fixed-capacity vectors that panic when full
+macro_rules! manage_fixed_vec {
+  ($v:expr, $idx:expr, $elem:expr) => {{
+    let capacity = $v.capacity();
+    if $v.len() >= capacity {
+      return Err(InsertError::new($elem, InsertErrorReason::CapacityExceeded { capacity }));
+    }
+    manage_vec!($v, $idx, $elem)
+  }};
+}
+
+/// Error of [`Insert`] and [`Push`](crate::Push) that gives back the rejected element.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InsertError<T> {
+  /// The element that couldn't be inserted
+  pub element: T,
+  /// Why the element couldn't be inserted
+  pub reason: InsertErrorReason,
+}
+
+impl<T> InsertError<T> {
+  /// Creates a new instance from a rejected `element` and its `reason`.
+  #[inline]
+  pub const fn new(element: T, reason: InsertErrorReason) -> Self {
+    Self { element, reason }
+  }
+
+  /// Gives back the rejected element.
+  #[inline]
+  pub fn into_inner(self) -> T {
+    self.element
+  }
+}
+
+impl<T> fmt::Display for InsertError<T> {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "element couldn't be inserted: {}", self.reason)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for InsertError<T> where T: fmt::Debug {}
+
+/// See [`InsertError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InsertErrorReason {
+  /// The element, or its key, is already stored.
+  AlreadyPresent,
+  /// The storage is full and can't grow.
+  CapacityExceeded {
+    /// Maximum number of elements
+    capacity: usize,
+  },
+  /// The referenced index is greater than the length.
+  IndexOutOfBounds {
+    /// Provided index
+    index: usize,
+    /// Length of the collection
+    len: usize,
+  },
+  /// The referenced byte index of a string doesn't lie on a `char` boundary.
+  NotCharBoundary {
+    /// Provided byte index
+    index: usize,
+  },
+}
+
+impl fmt::Display for InsertErrorReason {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match *self {
+      Self::AlreadyPresent => write!(f, "it is already present"),
+      Self::CapacityExceeded { capacity } => {
+        write!(f, "the capacity of {} would be exceeded", capacity)
+      }
+      Self::IndexOutOfBounds { index, len } => {
+        write!(f, "index {} is out of bounds for a length of {}", index, len)
+      }
+      Self::NotCharBoundary { index } => write!(f, "byte index {} is not a char boundary", index),
+    }
+  }
+}
+
 /// See [`insert`](Insert::insert) for more information.
 pub trait Insert {
   /// Error
@@ -33,6 +127,45 @@ pub trait Insert {
   fn insert(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error>;
 }
 
+/// ```rust
+/// let mut structure = None;
+/// cl_traits::Insert::insert(&mut &mut structure, 3);
+/// assert_eq!(structure, Some(3));
+/// ```
+impl<T> Insert for &'_ mut T
+where
+  T: Insert + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn insert(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).insert(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::Insert::insert(&mut structure, (0, 10));
+/// assert_eq!(structure.get(0), Some(&10));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Insert for Box<T>
+where
+  T: Insert + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn insert(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).insert(input)
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::b_tree_map();
 /// cl_traits::Insert::insert(&mut structure, (10, 100));
@@ -57,20 +190,24 @@ where
 /// let mut structure = cl_traits::doc_tests::b_tree_set();
 /// cl_traits::Insert::insert(&mut structure, 10);
 /// assert_eq!(structure.iter().find(|&&e| e == 10), Some(&10));
+/// assert_eq!(
+///   cl_traits::Insert::insert(&mut structure, 10).map_err(|err| err.reason),
+///   Err(cl_traits::InsertErrorReason::AlreadyPresent)
+/// );
 /// ```
 #[cfg(feature = "alloc")]
 impl<V> Insert for BTreeSet<V>
 where
   V: Ord,
 {
-  type Error = V;
+  type Error = InsertError<V>;
   type Input = V;
   type Ok = ();
 
   #[inline]
   fn insert(&mut self, v: Self::Input) -> Result<Self::Ok, Self::Error> {
     if self.contains(&v) {
-      Err(v)
+      Err(InsertError::new(v, InsertErrorReason::AlreadyPresent))
     } else {
       let _ = self.insert(v);
       Ok(())
@@ -108,14 +245,14 @@ impl<V> Insert for HashSet<V>
 where
   V: core::hash::Hash + Eq,
 {
-  type Error = V;
+  type Error = InsertError<V>;
   type Input = V;
   type Ok = ();
 
   #[inline]
   fn insert(&mut self, v: Self::Input) -> Result<Self::Ok, Self::Error> {
     if self.contains(&v) {
-      Err(v)
+      Err(InsertError::new(v, InsertErrorReason::AlreadyPresent))
     } else {
       let _ = self.insert(v);
       Ok(())
@@ -129,14 +266,14 @@ where
 /// assert_eq!(opt, Some(3));
 /// ```
 impl<T> Insert for Option<T> {
-  type Error = T;
+  type Error = InsertError<T>;
   type Input = T;
   type Ok = ();
 
   #[inline]
   fn insert(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
     if self.is_some() {
-      Err(input)
+      Err(InsertError::new(input, InsertErrorReason::CapacityExceeded { capacity: 1 }))
     } else {
       *self = Some(input);
       Ok(())
@@ -144,14 +281,33 @@ impl<T> Insert for Option<T> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::Insert::insert(&mut structure, (0, 10));
+/// assert_eq!(structure.get(0), Some(&10));
+/// ```
+impl<T, const N: usize> Insert for crate::ArrayStorage<T, N> {
+  type Error = InsertError<T>;
+  type Input = (usize, T);
+  type Ok = ();
+
+  #[inline]
+  fn insert(&mut self, (idx, elem): Self::Input) -> Result<Self::Ok, Self::Error> {
+    manage_fixed_vec!(self, idx, elem)
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::vec();
 /// cl_traits::Insert::insert(&mut structure, (0, 10));
 /// assert_eq!(structure.get(0), Some(&10));
+/// let err = cl_traits::Insert::insert(&mut structure, (10, 20)).unwrap_err();
+/// assert_eq!(err.reason, cl_traits::InsertErrorReason::IndexOutOfBounds { index: 10, len: 4 });
+/// assert_eq!(err.into_inner(), 20);
 /// ```
 #[cfg(feature = "alloc")]
 impl<T> Insert for Vec<T> {
-  type Error = T;
+  type Error = InsertError<T>;
   type Input = (usize, T);
   type Ok = ();
 
@@ -168,13 +324,221 @@ impl<T> Insert for Vec<T> {
 /// ```
 #[cfg(feature = "with-arrayvec")]
 impl<T, const N: usize> Insert for arrayvec::ArrayVec<T, N> {
-  type Error = T;
+  type Error = InsertError<T>;
   type Input = (usize, T);
   type Ok = ();
 
   #[inline]
   fn insert(&mut self, (idx, elem): Self::Input) -> Result<Self::Ok, Self::Error> {
-    manage_vec!(self, idx, elem)
+    manage_fixed_vec!(self, idx, elem)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// cl_traits::Insert::insert(&mut structure, (10, 100));
+/// assert_eq!(structure.get(&10), Some(&100));
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> Insert for hashbrown::HashMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = core::convert::Infallible;
+  type Input = (K, V);
+  type Ok = Option<V>;
+
+  #[inline]
+  fn insert(&mut self, (k, v): Self::Input) -> Result<Self::Ok, Self::Error> {
+    Ok(self.insert(k, v))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_set();
+/// cl_traits::Insert::insert(&mut structure, 10);
+/// assert!(cl_traits::Insert::insert(&mut structure, 10).is_err());
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> Insert for hashbrown::HashSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = InsertError<V>;
+  type Input = V;
+  type Ok = ();
+
+  #[inline]
+  fn insert(&mut self, v: Self::Input) -> Result<Self::Ok, Self::Error> {
+    if self.contains(&v) {
+      Err(InsertError::new(v, InsertErrorReason::AlreadyPresent))
+    } else {
+      let _ = self.insert(v);
+      Ok(())
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_deque();
+/// cl_traits::Insert::insert(&mut structure, (1, 10));
+/// assert_eq!(structure.iter().copied().collect::<Vec<_>>(), [1, 10, 2, 3]);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Insert for heapless::Deque<T, N> {
+  type Error = InsertError<T>;
+  type Input = (usize, T);
+  type Ok = ();
+
+  #[inline]
+  fn insert(&mut self, (idx, elem): Self::Input) -> Result<Self::Ok, Self::Error> {
+    if self.is_full() {
+      return Err(InsertError::new(elem, InsertErrorReason::CapacityExceeded { capacity: N }));
+    }
+    let len = self.len();
+    if idx > len {
+      return Err(InsertError::new(elem, InsertErrorReason::IndexOutOfBounds { index: idx, len }));
+    }
+    if let Err(elem) = self.push_back(elem) {
+      return Err(InsertError::new(elem, InsertErrorReason::CapacityExceeded { capacity: N }));
+    }
+    let mut curr = len;
+    while curr > idx {
+      let prev = curr.wrapping_sub(1);
+      let _ = crate::Swap::swap(self, [prev, curr]);
+      curr = prev;
+    }
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_index_map();
+/// cl_traits::Insert::insert(&mut structure, (10, 100));
+/// assert_eq!(structure.get(&10), Some(&100));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> Insert for heapless::IndexMap<K, V, S, N>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = InsertError<(K, V)>;
+  type Input = (K, V);
+  type Ok = Option<V>;
+
+  #[inline]
+  fn insert(&mut self, (k, v): Self::Input) -> Result<Self::Ok, Self::Error> {
+    self
+      .insert(k, v)
+      .map_err(|kv| InsertError::new(kv, InsertErrorReason::CapacityExceeded { capacity: N }))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_string();
+/// cl_traits::Insert::insert(&mut structure, (1, 'z'));
+/// assert_eq!(structure.as_str(), "azbc");
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> Insert for heapless::String<N> {
+  type Error = InsertError<char>;
+  type Input = (usize, char);
+  type Ok = ();
+
+  #[inline]
+  fn insert(&mut self, (idx, elem): Self::Input) -> Result<Self::Ok, Self::Error> {
+    let len = self.len();
+    if idx > len {
+      return Err(InsertError::new(elem, InsertErrorReason::IndexOutOfBounds { index: idx, len }));
+    }
+    if !self.is_char_boundary(idx) {
+      return Err(InsertError::new(elem, InsertErrorReason::NotCharBoundary { index: idx }));
+    }
+    if len.saturating_add(elem.len_utf8()) > N {
+      return Err(InsertError::new(elem, InsertErrorReason::CapacityExceeded { capacity: N }));
+    }
+    let mut tail = heapless::String::<N>::new();
+    let _ = tail.push_str(self.get(idx..).unwrap_or_default());
+    self.truncate(idx);
+    let _ = self.push(elem);
+    let _ = self.push_str(&tail);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_vec();
+/// cl_traits::Insert::insert(&mut structure, (0, 10));
+/// assert_eq!(structure.get(0), Some(&10));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Insert for heapless::Vec<T, N> {
+  type Error = InsertError<T>;
+  type Input = (usize, T);
+  type Ok = ();
+
+  #[inline]
+  fn insert(&mut self, (idx, elem): Self::Input) -> Result<Self::Ok, Self::Error> {
+    if self.is_full() {
+      return Err(InsertError::new(elem, InsertErrorReason::CapacityExceeded { capacity: N }));
+    }
+    let len = self.len();
+    if idx > len {
+      return Err(InsertError::new(elem, InsertErrorReason::IndexOutOfBounds { index: idx, len }));
+    }
+    self
+      .insert(idx, elem)
+      .map_err(|elem| InsertError::new(elem, InsertErrorReason::CapacityExceeded { capacity: N }))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// cl_traits::Insert::insert(&mut structure, (10, 100));
+/// assert_eq!(structure.get_index(3), Some((&10, &100)));
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Insert for indexmap::IndexMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = core::convert::Infallible;
+  type Input = (K, V);
+  type Ok = Option<V>;
+
+  #[inline]
+  fn insert(&mut self, (k, v): Self::Input) -> Result<Self::Ok, Self::Error> {
+    Ok(self.insert(k, v))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_set();
+/// cl_traits::Insert::insert(&mut structure, 10);
+/// assert!(cl_traits::Insert::insert(&mut structure, 10).is_err());
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Insert for indexmap::IndexSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = InsertError<V>;
+  type Input = V;
+  type Ok = ();
+
+  #[inline]
+  fn insert(&mut self, v: Self::Input) -> Result<Self::Ok, Self::Error> {
+    if self.contains(&v) {
+      Err(InsertError::new(v, InsertErrorReason::AlreadyPresent))
+    } else {
+      let _ = self.insert(v);
+      Ok(())
+    }
   }
 }
 
@@ -188,7 +552,7 @@ impl<A> Insert for smallvec::SmallVec<A>
 where
   A: smallvec::Array,
 {
-  type Error = A::Item;
+  type Error = InsertError<A::Item>;
   type Input = (usize, A::Item);
   type Ok = ();
 
@@ -205,13 +569,13 @@ where
 /// ```
 #[cfg(feature = "with-staticvec")]
 impl<T, const N: usize> Insert for staticvec::StaticVec<T, N> {
-  type Error = T;
+  type Error = InsertError<T>;
   type Input = (usize, T);
   type Ok = ();
 
   #[inline]
   fn insert(&mut self, (idx, elem): Self::Input) -> Result<Self::Ok, Self::Error> {
-    manage_vec!(self, idx, elem)
+    manage_fixed_vec!(self, idx, elem)
   }
 }
 
@@ -226,13 +590,13 @@ where
   A: tinyvec::Array,
   A::Item: Default,
 {
-  type Error = A::Item;
+  type Error = InsertError<A::Item>;
   type Input = (usize, A::Item);
   type Ok = ();
 
   #[inline]
   fn insert(&mut self, (idx, elem): Self::Input) -> Result<Self::Ok, Self::Error> {
-    manage_vec!(self, idx, elem)
+    manage_fixed_vec!(self, idx, elem)
   }
 }
 
@@ -247,7 +611,7 @@ where
   A: tinyvec::Array,
   A::Item: Default,
 {
-  type Error = A::Item;
+  type Error = InsertError<A::Item>;
   type Input = (usize, A::Item);
   type Ok = ();
 
@@ -0,0 +1,479 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+  boxed::Box,
+  collections::{
+    btree_map, btree_set, linked_list, vec_deque, BTreeMap, BTreeSet, LinkedList, VecDeque,
+  },
+  vec::Vec,
+};
+use core::{option, slice};
+#[cfg(feature = "std")]
+use std::collections::{hash_map, hash_set, HashMap, HashSet};
+
+/// See [`iter`](Iter::iter) for more information.
+pub trait Iter {
+  /// Output
+  type Output<'output>: Iterator
+  where
+    Self: 'output;
+
+  /// Iterates over shared references of all stored elements.
+  fn iter(&self) -> Self::Output<'_>;
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::Iter::iter(&&structure).sum::<i32>(), 6);
+/// ```
+impl<T> Iter for &'_ T
+where
+  T: Iter + ?Sized,
+{
+  type Output<'output>
+    = T::Output<'output>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    (**self).iter()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::Iter::iter(&&mut structure).sum::<i32>(), 6);
+/// ```
+impl<T> Iter for &'_ mut T
+where
+  T: Iter + ?Sized,
+{
+  type Output<'output>
+    = T::Output<'output>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    (**self).iter()
+  }
+}
+
+/// ```rust
+/// let structure = Box::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Iter for Box<T>
+where
+  T: Iter + ?Sized,
+{
+  type Output<'output>
+    = T::Output<'output>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    (**self).iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::b_tree_map();
+/// assert_eq!(cl_traits::Iter::iter(&structure).next(), Some((&0, &1)));
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> Iter for BTreeMap<K, V> {
+  type Output<'output>
+    = btree_map::Iter<'output, K, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::b_tree_set();
+/// assert_eq!(cl_traits::Iter::iter(&structure).next(), Some(&1));
+/// ```
+#[cfg(feature = "alloc")]
+impl<V> Iter for BTreeSet<V> {
+  type Output<'output>
+    = btree_set::Iter<'output, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hash_map();
+/// assert_eq!(cl_traits::Iter::iter(&structure).count(), 3);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> Iter for HashMap<K, V> {
+  type Output<'output>
+    = hash_map::Iter<'output, K, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hash_set();
+/// assert_eq!(cl_traits::Iter::iter(&structure).count(), 3);
+/// ```
+#[cfg(feature = "std")]
+impl<V> Iter for HashSet<V> {
+  type Output<'output>
+    = hash_set::Iter<'output, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let opt = Some(1);
+/// assert_eq!(cl_traits::Iter::iter(&opt).next(), Some(&1));
+/// ```
+impl<T> Iter for Option<T> {
+  type Output<'output>
+    = option::Iter<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+impl<T, const N: usize> Iter for [T; N] {
+  type Output<'output>
+    = slice::Iter<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.as_slice().iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::slice();
+/// assert_eq!(cl_traits::Iter::iter(structure).sum::<i32>(), 6);
+/// ```
+impl<T> Iter for [T] {
+  type Output<'output>
+    = slice::Iter<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    <[T]>::iter(self)
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::array_storage();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+impl<T, const N: usize> Iter for crate::ArrayStorage<T, N> {
+  type Output<'output>
+    = slice::Iter<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.as_slice().iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::vec();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Iter for Vec<T> {
+  type Output<'output>
+    = slice::Iter<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.as_slice().iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::linked_list();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Iter for LinkedList<T> {
+  type Output<'output>
+    = linked_list::Iter<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::vec_deque();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Iter for VecDeque<T> {
+  type Output<'output>
+    = vec_deque::Iter<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::array_vec();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> Iter for arrayvec::ArrayVec<T, N> {
+  type Output<'output>
+    = slice::Iter<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.as_slice().iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// assert_eq!(cl_traits::Iter::iter(&structure).count(), 3);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> Iter for hashbrown::HashMap<K, V, S> {
+  type Output<'output>
+    = hashbrown::hash_map::Iter<'output, K, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hashbrown_hash_set();
+/// assert_eq!(cl_traits::Iter::iter(&structure).count(), 3);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> Iter for hashbrown::HashSet<V, S> {
+  type Output<'output>
+    = hashbrown::hash_set::Iter<'output, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_deque();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Iter for heapless::Deque<T, N> {
+  type Output<'output>
+    = core::iter::Chain<slice::Iter<'output, T>, slice::Iter<'output, T>>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    let (front, back) = self.as_slices();
+    front.iter().chain(back.iter())
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_index_map();
+/// assert_eq!(cl_traits::Iter::iter(&structure).count(), 3);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> Iter for heapless::IndexMap<K, V, S, N> {
+  type Output<'output>
+    = heapless::IndexMapIter<'output, K, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_vec();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Iter for heapless::Vec<T, N> {
+  type Output<'output>
+    = slice::Iter<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.as_slice().iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::index_map();
+/// assert_eq!(cl_traits::Iter::iter(&structure).count(), 3);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Iter for indexmap::IndexMap<K, V, S> {
+  type Output<'output>
+    = indexmap::map::Iter<'output, K, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::index_set();
+/// assert_eq!(cl_traits::Iter::iter(&structure).count(), 3);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Iter for indexmap::IndexSet<V, S> {
+  type Output<'output>
+    = indexmap::set::Iter<'output, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::small_vec();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> Iter for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Output<'output>
+    = slice::Iter<'output, A::Item>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.as_slice().iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::static_vec();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> Iter for staticvec::StaticVec<T, N> {
+  type Output<'output>
+    = slice::Iter<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.as_slice().iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> Iter for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Output<'output>
+    = slice::Iter<'output, A::Item>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.as_slice().iter()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::tiny_vec();
+/// assert_eq!(cl_traits::Iter::iter(&structure).sum::<i32>(), 6);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> Iter for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Output<'output>
+    = slice::Iter<'output, A::Item>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter(&self) -> Self::Output<'_> {
+    self.as_slice().iter()
+  }
+}
@@ -0,0 +1,411 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+  boxed::Box,
+  collections::{btree_map, linked_list, vec_deque, BTreeMap, LinkedList, VecDeque},
+  vec::Vec,
+};
+use core::{option, slice};
+#[cfg(feature = "std")]
+use std::collections::{hash_map, HashMap};
+
+/// See [`iter_mut`](IterMut::iter_mut) for more information.
+pub trait IterMut {
+  /// Output
+  type Output<'output>: Iterator
+  where
+    Self: 'output;
+
+  /// Iterates over exclusive references of all stored elements.
+  fn iter_mut(&mut self) -> Self::Output<'_>;
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// cl_traits::IterMut::iter_mut(&mut &mut structure).for_each(|e| *e += 1);
+/// assert_eq!(structure, [2, 3, 4]);
+/// ```
+impl<T> IterMut for &'_ mut T
+where
+  T: IterMut + ?Sized,
+{
+  type Output<'output>
+    = T::Output<'output>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    (**self).iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(&structure[..], &[2, 3, 4]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> IterMut for Box<T>
+where
+  T: IterMut + ?Sized,
+{
+  type Output<'output>
+    = T::Output<'output>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    (**self).iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::b_tree_map();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|(_, v)| *v += 1);
+/// assert_eq!(structure.get(&0), Some(&2));
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> IterMut for BTreeMap<K, V> {
+  type Output<'output>
+    = btree_map::IterMut<'output, K, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_map();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|(_, v)| *v += 1);
+/// assert_eq!(structure.get(&0), Some(&2));
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> IterMut for HashMap<K, V> {
+  type Output<'output>
+    = hash_map::IterMut<'output, K, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut opt = Some(1);
+/// cl_traits::IterMut::iter_mut(&mut opt).for_each(|e| *e += 1);
+/// assert_eq!(opt, Some(2));
+/// ```
+impl<T> IterMut for Option<T> {
+  type Output<'output>
+    = option::IterMut<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(structure, [2, 3, 4]);
+/// ```
+impl<T, const N: usize> IterMut for [T; N] {
+  type Output<'output>
+    = slice::IterMut<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.as_mut_slice().iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut array = [1, 2, 3];
+/// cl_traits::IterMut::iter_mut(&mut array[..]).for_each(|e| *e += 1);
+/// assert_eq!(array, [2, 3, 4]);
+/// ```
+impl<T> IterMut for [T] {
+  type Output<'output>
+    = slice::IterMut<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    <[T]>::iter_mut(self)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(&structure[..], &[2, 3, 4]);
+/// ```
+impl<T, const N: usize> IterMut for crate::ArrayStorage<T, N> {
+  type Output<'output>
+    = slice::IterMut<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.as_mut_slice().iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(&structure[..], &[2, 3, 4]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> IterMut for Vec<T> {
+  type Output<'output>
+    = slice::IterMut<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.as_mut_slice().iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::linked_list();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(structure.front(), Some(&2));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> IterMut for LinkedList<T> {
+  type Output<'output>
+    = linked_list::IterMut<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(structure, [2, 3, 4]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> IterMut for VecDeque<T> {
+  type Output<'output>
+    = vec_deque::IterMut<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(&structure[..], &[2, 3, 4]);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> IterMut for arrayvec::ArrayVec<T, N> {
+  type Output<'output>
+    = slice::IterMut<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.as_mut_slice().iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|(_, v)| *v += 1);
+/// assert_eq!(structure.get(&0), Some(&2));
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> IterMut for hashbrown::HashMap<K, V, S> {
+  type Output<'output>
+    = hashbrown::hash_map::IterMut<'output, K, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_deque();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert!(structure.iter().eq([2, 3, 4].iter()));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> IterMut for heapless::Deque<T, N> {
+  type Output<'output>
+    = core::iter::Chain<slice::IterMut<'output, T>, slice::IterMut<'output, T>>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    let (front, back) = self.as_mut_slices();
+    front.iter_mut().chain(back.iter_mut())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_index_map();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|(_, v)| *v += 1);
+/// assert_eq!(structure.get(&0), Some(&2));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> IterMut for heapless::IndexMap<K, V, S, N> {
+  type Output<'output>
+    = heapless::IndexMapIterMut<'output, K, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_vec();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(&structure[..], &[2, 3, 4]);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> IterMut for heapless::Vec<T, N> {
+  type Output<'output>
+    = slice::IterMut<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.as_mut_slice().iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|(_, v)| *v += 1);
+/// assert_eq!(structure.get(&0), Some(&2));
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> IterMut for indexmap::IndexMap<K, V, S> {
+  type Output<'output>
+    = indexmap::map::IterMut<'output, K, V>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(&structure[..], &[2, 3, 4]);
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> IterMut for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Output<'output>
+    = slice::IterMut<'output, A::Item>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.as_mut_slice().iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(&structure[..], &[2, 3, 4]);
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> IterMut for staticvec::StaticVec<T, N> {
+  type Output<'output>
+    = slice::IterMut<'output, T>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.as_mut_slice().iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(&structure[..], &[2, 3, 4]);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> IterMut for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Output<'output>
+    = slice::IterMut<'output, A::Item>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.as_mut_slice().iter_mut()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// cl_traits::IterMut::iter_mut(&mut structure).for_each(|e| *e += 1);
+/// assert_eq!(&structure[..], &[2, 3, 4]);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> IterMut for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Output<'output>
+    = slice::IterMut<'output, A::Item>
+  where
+    Self: 'output;
+
+  #[inline]
+  fn iter_mut(&mut self) -> Self::Output<'_> {
+    self.as_mut_slice().iter_mut()
+  }
+}
@@ -1,5 +1,15 @@
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+  rc::Rc,
+  string::String,
+  vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// See [`length`](Length::length) for more information.
 pub trait Length {
@@ -7,6 +17,127 @@ pub trait Length {
   fn length(&self) -> usize;
 }
 
+/// ```rust
+/// let structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::Length::length(&&structure), 3);
+/// ```
+impl<T> Length for &'_ T
+where
+  T: Length + ?Sized,
+{
+  #[inline]
+  fn length(&self) -> usize {
+    (**self).length()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// assert_eq!(cl_traits::Length::length(&&mut structure), 3);
+/// ```
+impl<T> Length for &'_ mut T
+where
+  T: Length + ?Sized,
+{
+  #[inline]
+  fn length(&self) -> usize {
+    (**self).length()
+  }
+}
+
+/// ```rust
+/// let structure = Box::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Length for Box<T>
+where
+  T: Length + ?Sized,
+{
+  #[inline]
+  fn length(&self) -> usize {
+    (**self).length()
+  }
+}
+
+/// ```rust
+/// let structure = std::rc::Rc::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Length for Rc<T>
+where
+  T: Length + ?Sized,
+{
+  #[inline]
+  fn length(&self) -> usize {
+    (**self).length()
+  }
+}
+
+/// ```rust
+/// let structure = std::sync::Arc::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> Length for Arc<T>
+where
+  T: Length + ?Sized,
+{
+  #[inline]
+  fn length(&self) -> usize {
+    (**self).length()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::b_tree_map();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> Length for BTreeMap<K, V> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::b_tree_set();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<V> Length for BTreeSet<V> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hash_map();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> Length for HashMap<K, V> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hash_set();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "std")]
+impl<V> Length for HashSet<V> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
 /// ```rust
 /// let mut opt = Some(0);
 /// assert_eq!(cl_traits::Length::length(&opt), 1);
@@ -26,9 +157,9 @@ impl<T> Length for Option<T> {
 
 /// ```rust
 /// let structure = cl_traits::doc_tests::slice();
-/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// assert_eq!(cl_traits::Length::length(structure), 3);
 /// ```
-impl<T> Length for &'_ [T] {
+impl<T> Length for [T] {
   #[inline]
   fn length(&self) -> usize {
     self.len()
@@ -36,9 +167,10 @@ impl<T> Length for &'_ [T] {
 }
 
 /// ```rust
-/// assert_eq!(cl_traits::Length::length(&&mut [1, 2, 3][..]), 3);
+/// let structure = cl_traits::doc_tests::array_storage();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
 /// ```
-impl<T> Length for &'_ mut [T] {
+impl<T, const N: usize> Length for crate::ArrayStorage<T, N> {
   #[inline]
   fn length(&self) -> usize {
     self.len()
@@ -56,6 +188,18 @@ impl<T, const N: usize> Length for [T; N] {
   }
 }
 
+/// ```rust
+/// let structure = cl_traits::doc_tests::string();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl Length for String {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
 /// ```rust
 /// let structure = cl_traits::doc_tests::vec();
 /// assert_eq!(cl_traits::Length::length(&structure), 3);
@@ -68,6 +212,42 @@ impl<T> Length for Vec<T> {
   }
 }
 
+/// ```rust
+/// let structure = cl_traits::doc_tests::linked_list();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Length for LinkedList<T> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::vec_deque();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Length for VecDeque<T> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::array_string();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<const CAP: usize> Length for arrayvec::ArrayString<CAP> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
 /// ```rust
 /// let structure = cl_traits::doc_tests::array_vec();
 /// assert_eq!(cl_traits::Length::length(&structure), 3);
@@ -80,6 +260,102 @@ impl<T, const N: usize> Length for arrayvec::ArrayVec<T, N> {
   }
 }
 
+/// ```rust
+/// let structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> Length for hashbrown::HashMap<K, V, S> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::hashbrown_hash_set();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> Length for hashbrown::HashSet<V, S> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_deque();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Length for heapless::Deque<T, N> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_index_map();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> Length for heapless::IndexMap<K, V, S, N> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_string();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> Length for heapless::String<N> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::heapless_vec();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Length for heapless::Vec<T, N> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::index_map();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Length for indexmap::IndexMap<K, V, S> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
+/// ```rust
+/// let structure = cl_traits::doc_tests::index_set();
+/// assert_eq!(cl_traits::Length::length(&structure), 3);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Length for indexmap::IndexSet<V, S> {
+  #[inline]
+  fn length(&self) -> usize {
+    self.len()
+  }
+}
+
 /// ```rust
 /// let structure = cl_traits::doc_tests::small_vec();
 /// assert_eq!(cl_traits::Length::length(&structure), 3);
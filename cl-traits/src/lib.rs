@@ -9,31 +9,83 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "with-arbitrary")]
+pub mod arbitrary;
+mod array_storage;
 mod capacity;
 mod capacity_upper_bound;
 mod clear;
+mod const_capacity;
 pub mod doc_tests;
+mod get;
+mod get_mut;
 mod insert;
+mod iter;
+mod iter_mut;
 mod length;
+#[cfg(any(feature = "with-arbitrary", feature = "with-proptest"))]
+mod operation;
+mod pop;
+mod pop_front;
+#[cfg(feature = "with-proptest")]
+pub mod proptest;
 mod push;
+mod push_char;
+mod push_front;
+mod push_many;
+mod push_str;
 mod remove;
+mod reserve;
+mod reserve_exact;
 mod retain;
+mod retain_map;
+mod retain_mut;
+#[cfg(feature = "with-serde")]
+pub mod serde;
+mod shrink_to_fit;
 mod storage;
 mod swap;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod truncate;
+mod try_reserve;
+mod try_with_capacity;
 mod utils;
 mod with_capacity;
 
+pub use array_storage::*;
 pub use capacity::*;
 pub use capacity_upper_bound::*;
+#[cfg(feature = "derive")]
+pub use cl_traits_derive::*;
 pub use clear::*;
+pub use const_capacity::*;
+pub use get::*;
+pub use get_mut::*;
 pub use insert::*;
+pub use iter::*;
+pub use iter_mut::*;
 pub use length::*;
+#[cfg(any(feature = "with-arbitrary", feature = "with-proptest"))]
+pub use operation::*;
+pub use pop::*;
+pub use pop_front::*;
 pub use push::*;
+pub use push_char::*;
+pub use push_front::*;
+pub use push_many::*;
+pub use push_str::*;
 pub use remove::*;
+pub use reserve::*;
+pub use reserve_exact::*;
 pub use retain::*;
+pub use retain_map::*;
+pub use retain_mut::*;
+pub use shrink_to_fit::*;
 pub use storage::*;
 pub use swap::*;
 pub use truncate::*;
+pub use try_reserve::*;
+pub use try_with_capacity::*;
 pub use utils::*;
 pub use with_capacity::*;
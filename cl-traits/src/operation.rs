@@ -0,0 +1,73 @@
+use crate::{Clear, Insert, Push, Remove, Retain, Swap, Truncate};
+
+/// A single call to one of the vector-like traits, generated by the property testing
+/// integrations.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operation<T> {
+  /// See [`Clear`]
+  Clear,
+  /// See [`Insert`], with an index and an element
+  Insert(usize, T),
+  /// See [`Push`]
+  Push(T),
+  /// See [`Remove`], with an index
+  Remove(usize),
+  /// See [`Retain`]. The `n`-th visited element is kept if the `n % 64` bit of the mask is set.
+  Retain(u64),
+  /// See [`Swap`]
+  Swap([usize; 2]),
+  /// See [`Truncate`]
+  Truncate(usize),
+}
+
+impl<T> Operation<T> {
+  /// Applies the operation to `storage`, discarding results and removed elements.
+  ///
+  /// ```rust
+  /// use cl_traits::Operation;
+  /// let mut structure = cl_traits::doc_tests::array_storage();
+  /// for op in [Operation::Push(4), Operation::Swap([0, 3]), Operation::Retain(0b1011)] {
+  ///   op.apply(&mut structure);
+  /// }
+  /// assert_eq!(&structure[..], &[4, 2, 1]);
+  /// ```
+  #[inline]
+  pub fn apply<'storage, S>(self, storage: &mut S)
+  where
+    S: Clear
+      + Insert<Input = (usize, T)>
+      + Push<Input = T>
+      + Remove<Input<'storage> = usize>
+      + Retain<Input = T>
+      + Swap<Input = [usize; 2]>
+      + Truncate<Input = usize>
+      + 'storage,
+  {
+    match self {
+      Self::Clear => storage.clear(),
+      Self::Insert(idx, elem) => {
+        let _ = storage.insert((idx, elem));
+      }
+      Self::Push(elem) => {
+        let _ = storage.push(elem);
+      }
+      Self::Remove(idx) => {
+        let _ = storage.remove(idx);
+      }
+      Self::Retain(mask) => {
+        let mut nth: u32 = 0;
+        let _ = storage.retain(|_: &T| {
+          let keep = mask.wrapping_shr(nth) & 1 == 1;
+          nth = nth.wrapping_add(1);
+          keep
+        });
+      }
+      Self::Swap(indices) => {
+        let _ = storage.swap(indices);
+      }
+      Self::Truncate(len) => {
+        let _ = storage.truncate(len);
+      }
+    }
+  }
+}
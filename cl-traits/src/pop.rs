@@ -0,0 +1,255 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+  boxed::Box,
+  collections::{LinkedList, VecDeque},
+  vec::Vec,
+};
+
+/// See [`pop`](Pop::pop) for more information.
+pub trait Pop {
+  /// Error
+  type Error;
+  /// Ok
+  type Ok;
+
+  /// Pops the last element, decreasing the storage length.
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error>;
+}
+
+/// ```rust
+/// let mut structure = Some(1);
+/// assert_eq!(cl_traits::Pop::pop(&mut &mut structure), Ok(1));
+/// assert_eq!(structure, None);
+/// ```
+impl<T> Pop for &'_ mut T
+where
+  T: Pop + ?Sized,
+{
+  type Error = T::Error;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    (**self).pop()
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Pop for Box<T>
+where
+  T: Pop + ?Sized,
+{
+  type Error = T::Error;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    (**self).pop()
+  }
+}
+
+/// ```rust
+/// let mut opt = Some(3);
+/// assert_eq!(cl_traits::Pop::pop(&mut opt), Ok(3));
+/// assert_eq!(opt, None);
+/// ```
+impl<T> Pop for Option<T> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.take().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+impl<T, const N: usize> Pop for crate::ArrayStorage<T, N> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Pop for Vec<T> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::linked_list();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Pop for LinkedList<T> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop_back().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Pop for VecDeque<T> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop_back().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> Pop for arrayvec::ArrayVec<T, N> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok((2, 3)));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Pop for indexmap::IndexMap<K, V, S> {
+  type Error = ();
+  type Ok = (K, V);
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_set();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Pop for indexmap::IndexSet<V, S> {
+  type Error = ();
+  type Ok = V;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> Pop for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Error = ();
+  type Ok = A::Item;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> Pop for staticvec::StaticVec<T, N> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> Pop for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ();
+  type Ok = A::Item;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// assert_eq!(cl_traits::Pop::pop(&mut structure), Ok(3));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> Pop for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ();
+  type Ok = A::Item;
+
+  #[inline]
+  fn pop(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop().ok_or(())
+  }
+}
@@ -0,0 +1,224 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+  boxed::Box,
+  collections::{LinkedList, VecDeque},
+  vec::Vec,
+};
+
+#[allow(
+  // For convenience because of selected features
+  unused
+)]
+// Manages vectors that don't perform bound checks
+macro_rules! manage_vec {
+  ($v:expr) => {{
+    if $v.is_empty() {
+      return Err(());
+    }
+    Ok($v.remove(0))
+  }};
+}
+
+/// See [`pop_front`](PopFront::pop_front) for more information.
+pub trait PopFront {
+  /// Error
+  type Error;
+  /// Ok
+  type Ok;
+
+  /// Pops the first element, decreasing the storage length.
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error>;
+}
+
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// let mut structure = cl_traits::doc_tests::vec();
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut &mut structure), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// # }
+/// ```
+impl<T> PopFront for &'_ mut T
+where
+  T: PopFront + ?Sized,
+{
+  type Error = T::Error;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    (**self).pop_front()
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut structure), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PopFront for Box<T>
+where
+  T: PopFront + ?Sized,
+{
+  type Error = T::Error;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    (**self).pop_front()
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::linked_list();
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut structure), Ok(1));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PopFront for LinkedList<T> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop_front().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut structure), Ok(1));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PopFront for VecDeque<T> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    self.pop_front().ok_or(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut structure), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// ```
+impl<T, const N: usize> PopFront for crate::ArrayStorage<T, N> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    manage_vec!(self)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut structure), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PopFront for Vec<T> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    manage_vec!(self)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut structure), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> PopFront for arrayvec::ArrayVec<T, N> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    manage_vec!(self)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut structure), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> PopFront for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Error = ();
+  type Ok = A::Item;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    manage_vec!(self)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut structure), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> PopFront for staticvec::StaticVec<T, N> {
+  type Error = ();
+  type Ok = T;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    manage_vec!(self)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut structure), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> PopFront for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ();
+  type Ok = A::Item;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    manage_vec!(self)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// assert_eq!(cl_traits::PopFront::pop_front(&mut structure), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> PopFront for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ();
+  type Ok = A::Item;
+
+  #[inline]
+  fn pop_front(&mut self) -> Result<Self::Ok, Self::Error> {
+    manage_vec!(self)
+  }
+}
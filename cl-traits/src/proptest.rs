@@ -0,0 +1,95 @@
+//! Strategies of the `proptest` crate for storages and operations that are generic over the
+//! traits of this crate.
+
+use crate::{CapacityUpperBound, Operation, Push, WithCapacity};
+use ::proptest::{
+  collection::{vec, SizeRange},
+  prelude::{any, Just, Strategy},
+  prop_oneof,
+};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Storage filled with elements generated by `element`.
+///
+/// The number of elements is taken from `size` but never exceeds the
+/// [`CapacityUpperBound`] of `S`.
+///
+/// ```rust
+/// use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+/// let mut runner = TestRunner::default();
+/// let strategy =
+///   cl_traits::proptest::collection::<cl_traits::ArrayStorage<u8, 4>, _>(any::<u8>(), 2..10);
+/// let structure = strategy.new_tree(&mut runner).unwrap().current();
+/// assert!((2..=4).contains(&structure.len()));
+/// ```
+#[inline]
+pub fn collection<S, T>(element: T, size: impl Into<SizeRange>) -> impl Strategy<Value = S>
+where
+  S: CapacityUpperBound + Debug + Push<Input = T::Value> + WithCapacity<Input = usize>,
+  T: Strategy,
+{
+  let upper_bound = S::with_capacity(0).capacity_upper_bound();
+  let (start, end) = size.into().start_end_incl();
+  let end = end.min(upper_bound);
+  vec(element, start.min(end)..=end).prop_map(|elems| {
+    let mut storage = S::with_capacity(elems.len());
+    for elem in elems {
+      let _ = storage.push(elem);
+    }
+    storage
+  })
+}
+
+/// [`Operation`] whose elements are generated by `element` and whose indices lie within
+/// `0..=max_index`.
+///
+/// A `max_index` greater than the length of the storage also covers out-of-bounds accesses.
+///
+/// ```rust
+/// use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+/// let mut runner = TestRunner::default();
+/// let strategy = cl_traits::proptest::operation(any::<u8>(), 3);
+/// if let cl_traits::Operation::Truncate(len) = strategy.new_tree(&mut runner).unwrap().current() {
+///   assert!(len <= 3);
+/// }
+/// ```
+#[inline]
+pub fn operation<T>(element: T, max_index: usize) -> impl Strategy<Value = Operation<T::Value>>
+where
+  T: Clone + Strategy,
+{
+  prop_oneof![
+    Just(()).prop_map(|_| Operation::Clear),
+    (0..=max_index, element.clone()).prop_map(|(idx, elem)| Operation::Insert(idx, elem)),
+    element.prop_map(Operation::Push),
+    (0..=max_index).prop_map(Operation::Remove),
+    any::<u64>().prop_map(Operation::Retain),
+    (0..=max_index, 0..=max_index).prop_map(|(a, b)| Operation::Swap([a, b])),
+    (0..=max_index).prop_map(Operation::Truncate),
+  ]
+}
+
+/// Sequence of [`operation`]s whose length is taken from `size`.
+///
+/// ```rust
+/// use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+/// let mut runner = TestRunner::default();
+/// let ops = cl_traits::proptest::operations(any::<u8>(), 8, 0..32);
+/// let mut structure = cl_traits::ArrayStorage::<u8, 4>::new();
+/// for op in ops.new_tree(&mut runner).unwrap().current() {
+///   op.apply(&mut structure);
+/// }
+/// assert!(structure.len() <= 4);
+/// ```
+#[inline]
+pub fn operations<T>(
+  element: T,
+  max_index: usize,
+  size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Vec<Operation<T::Value>>>
+where
+  T: Clone + Strategy,
+{
+  vec(operation(element, max_index), size)
+}
@@ -1,5 +1,10 @@
+use crate::{InsertError, InsertErrorReason};
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{
+  boxed::Box,
+  collections::{LinkedList, VecDeque},
+  vec::Vec,
+};
 
 /// See [`push`](Push::push) for more information.
 pub trait Push {
@@ -14,20 +19,59 @@ pub trait Push {
   fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error>;
 }
 
+/// ```rust
+/// let mut structure = None;
+/// cl_traits::Push::push(&mut &mut structure, 3);
+/// assert_eq!(structure, Some(3));
+/// ```
+impl<T> Push for &'_ mut T
+where
+  T: Push + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).push(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::Push::push(&mut structure, 20);
+/// assert_eq!(structure.get(3), Some(&20));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Push for Box<T>
+where
+  T: Push + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).push(input)
+  }
+}
+
 /// ```rust
 /// let mut opt = None;
 /// cl_traits::Push::push(&mut opt, 3);
 /// assert_eq!(opt, Some(3));
 /// ```
 impl<T> Push for Option<T> {
-  type Error = T;
+  type Error = InsertError<T>;
   type Input = T;
   type Ok = ();
 
   #[inline]
   fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
     if self.is_some() {
-      Err(input)
+      Err(InsertError::new(input, InsertErrorReason::CapacityExceeded { capacity: 1 }))
     } else {
       *self = Some(input);
       Ok(())
@@ -35,6 +79,24 @@ impl<T> Push for Option<T> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::Push::push(&mut structure, 20);
+/// assert_eq!(structure.get(3), Some(&20));
+/// ```
+impl<T, const N: usize> Push for crate::ArrayStorage<T, N> {
+  type Error = InsertError<T>;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self
+      .try_push(input)
+      .map_err(|elem| InsertError::new(elem, InsertErrorReason::CapacityExceeded { capacity: N }))
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::vec();
 /// cl_traits::Push::push(&mut structure, 20);
@@ -53,20 +115,145 @@ impl<T> Push for Vec<T> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::linked_list();
+/// cl_traits::Push::push(&mut structure, 20);
+/// assert_eq!(structure.back(), Some(&20));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Push for LinkedList<T> {
+  type Error = core::convert::Infallible;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.push_back(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::Push::push(&mut structure, 20);
+/// assert_eq!(structure.get(3), Some(&20));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Push for VecDeque<T> {
+  type Error = core::convert::Infallible;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.push_back(input);
+    Ok(())
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::array_vec();
 /// cl_traits::Push::push(&mut structure, 20);
 /// assert_eq!(structure.get(3), Some(&20));
+/// cl_traits::Push::push(&mut structure, 30);
+/// let err = cl_traits::Push::push(&mut structure, 40).unwrap_err();
+/// assert_eq!(err.reason, cl_traits::InsertErrorReason::CapacityExceeded { capacity: 5 });
+/// assert_eq!(err.into_inner(), 40);
 /// ```
 #[cfg(feature = "with-arrayvec")]
 impl<T, const N: usize> Push for arrayvec::ArrayVec<T, N> {
-  type Error = T;
+  type Error = InsertError<T>;
   type Input = T;
   type Ok = ();
 
   #[inline]
   fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
-    self.try_push(input).map_err(|e| e.element())
+    self.try_push(input).map_err(|e| {
+      InsertError::new(e.element(), InsertErrorReason::CapacityExceeded { capacity: N })
+    })
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_deque();
+/// cl_traits::Push::push(&mut structure, 20);
+/// assert_eq!(structure.back(), Some(&20));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Push for heapless::Deque<T, N> {
+  type Error = InsertError<T>;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self
+      .push_back(input)
+      .map_err(|elem| InsertError::new(elem, InsertErrorReason::CapacityExceeded { capacity: N }))
+  }
+}
+
+/// Pushing an existing key replaces and returns its value without increasing the length.
+///
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_index_map();
+/// assert_eq!(cl_traits::Push::push(&mut structure, (10, 100)), Ok(None));
+/// assert_eq!(cl_traits::Push::push(&mut structure, (10, 200)), Ok(Some(100)));
+/// assert_eq!(structure.get(&10), Some(&200));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> Push for heapless::IndexMap<K, V, S, N>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = InsertError<(K, V)>;
+  type Input = (K, V);
+  type Ok = Option<V>;
+
+  #[inline]
+  fn push(&mut self, (k, v): Self::Input) -> Result<Self::Ok, Self::Error> {
+    self
+      .insert(k, v)
+      .map_err(|kv| InsertError::new(kv, InsertErrorReason::CapacityExceeded { capacity: N }))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_string();
+/// cl_traits::Push::push(&mut structure, 'd');
+/// assert_eq!(structure.as_str(), "abcd");
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> Push for heapless::String<N> {
+  type Error = InsertError<char>;
+  type Input = char;
+  type Ok = ();
+
+  #[inline]
+  fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self
+      .push(input)
+      .map_err(|_| InsertError::new(input, InsertErrorReason::CapacityExceeded { capacity: N }))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_vec();
+/// cl_traits::Push::push(&mut structure, 20);
+/// assert_eq!(structure.get(3), Some(&20));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Push for heapless::Vec<T, N> {
+  type Error = InsertError<T>;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self
+      .push(input)
+      .map_err(|elem| InsertError::new(elem, InsertErrorReason::CapacityExceeded { capacity: N }))
   }
 }
 
@@ -80,7 +267,7 @@ impl<A> Push for smallvec::SmallVec<A>
 where
   A: smallvec::Array,
 {
-  type Error = A::Item;
+  type Error = core::convert::Infallible;
   type Input = A::Item;
   type Ok = ();
 
@@ -98,13 +285,15 @@ where
 /// ```
 #[cfg(feature = "with-staticvec")]
 impl<T, const N: usize> Push for staticvec::StaticVec<T, N> {
-  type Error = T;
+  type Error = InsertError<T>;
   type Input = T;
   type Ok = ();
 
   #[inline]
   fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
-    self.try_push(input).map_err(|e| e.into_value())
+    self.try_push(input).map_err(|e| {
+      InsertError::new(e.into_value(), InsertErrorReason::CapacityExceeded { capacity: N })
+    })
   }
 }
 
@@ -119,7 +308,7 @@ where
   A: tinyvec::Array,
   A::Item: Default,
 {
-  type Error = A::Item;
+  type Error = InsertError<A::Item>;
   type Input = A::Item;
   type Ok = ();
 
@@ -127,7 +316,9 @@ where
   fn push(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
     match self.try_push(input) {
       None => Ok(()),
-      Some(rslt) => Err(rslt),
+      Some(rslt) => {
+        Err(InsertError::new(rslt, InsertErrorReason::CapacityExceeded { capacity: A::CAPACITY }))
+      }
     }
   }
 }
@@ -143,7 +334,7 @@ where
   A: tinyvec::Array,
   A::Item: Default,
 {
-  type Error = A::Item;
+  type Error = core::convert::Infallible;
   type Input = A::Item;
   type Ok = ();
 
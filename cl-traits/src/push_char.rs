@@ -0,0 +1,149 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String};
+
+/// See [`push_char`](PushChar::push_char) for more information.
+pub trait PushChar {
+  /// Error
+  type Error;
+  /// Ok
+  type Ok;
+
+  /// Appends a `char` to the end of the text buffer, increasing its length.
+  fn push_char(&mut self, input: char) -> Result<Self::Ok, Self::Error>;
+}
+
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// let mut structure = String::from("ab");
+/// cl_traits::PushChar::push_char(&mut &mut structure, 'c');
+/// assert_eq!(structure, "abc");
+/// # }
+/// ```
+impl<T> PushChar for &'_ mut T
+where
+  T: PushChar + ?Sized,
+{
+  type Error = T::Error;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn push_char(&mut self, input: char) -> Result<Self::Ok, Self::Error> {
+    (**self).push_char(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(String::from("ab"));
+/// cl_traits::PushChar::push_char(&mut structure, 'c');
+/// assert_eq!(structure.as_str(), "abc");
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PushChar for Box<T>
+where
+  T: PushChar + ?Sized,
+{
+  type Error = T::Error;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn push_char(&mut self, input: char) -> Result<Self::Ok, Self::Error> {
+    (**self).push_char(input)
+  }
+}
+
+/// ```rust
+/// let mut structure: Box<str> = "ab".into();
+/// cl_traits::PushChar::push_char(&mut structure, 'c');
+/// assert_eq!(&*structure, "abc");
+/// ```
+#[cfg(feature = "alloc")]
+impl PushChar for Box<str> {
+  type Error = core::convert::Infallible;
+  type Ok = ();
+
+  #[inline]
+  fn push_char(&mut self, input: char) -> Result<Self::Ok, Self::Error> {
+    let mut string = String::from(core::mem::take(self));
+    string.push(input);
+    *self = string.into_boxed_str();
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::string();
+/// cl_traits::PushChar::push_char(&mut structure, 'd');
+/// assert_eq!(structure, "abcd");
+/// ```
+#[cfg(feature = "alloc")]
+impl PushChar for String {
+  type Error = core::convert::Infallible;
+  type Ok = ();
+
+  #[inline]
+  fn push_char(&mut self, input: char) -> Result<Self::Ok, Self::Error> {
+    self.push(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_string();
+/// cl_traits::PushChar::push_char(&mut structure, 'd');
+/// assert_eq!(structure.as_str(), "abcd");
+/// let err = cl_traits::PushChar::push_char(&mut structure, 'é').unwrap_err();
+/// assert_eq!(err.element, 'é');
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<const CAP: usize> PushChar for arrayvec::ArrayString<CAP> {
+  type Error = crate::InsertError<char>;
+  type Ok = ();
+
+  #[inline]
+  fn push_char(&mut self, input: char) -> Result<Self::Ok, Self::Error> {
+    self.try_push(input).map_err(|e| {
+      crate::InsertError::new(
+        e.element(),
+        crate::InsertErrorReason::CapacityExceeded { capacity: CAP },
+      )
+    })
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_string();
+/// cl_traits::PushChar::push_char(&mut structure, 'd');
+/// assert_eq!(structure.as_str(), "abcd");
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> PushChar for heapless::String<N> {
+  type Error = crate::InsertError<char>;
+  type Ok = ();
+
+  #[inline]
+  fn push_char(&mut self, input: char) -> Result<Self::Ok, Self::Error> {
+    self.push(input).map_err(|_| {
+      crate::InsertError::new(input, crate::InsertErrorReason::CapacityExceeded { capacity: N })
+    })
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_string();
+/// cl_traits::PushChar::push_char(&mut structure, 'd');
+/// assert_eq!(structure.as_str(), "abcd");
+/// ```
+#[cfg(feature = "with-smallstr")]
+impl<A> PushChar for smallstr::SmallString<A>
+where
+  A: smallvec::Array<Item = u8>,
+{
+  type Error = core::convert::Infallible;
+  type Ok = ();
+
+  #[inline]
+  fn push_char(&mut self, input: char) -> Result<Self::Ok, Self::Error> {
+    self.push(input);
+    Ok(())
+  }
+}
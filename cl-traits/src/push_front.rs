@@ -0,0 +1,253 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+  boxed::Box,
+  collections::{LinkedList, VecDeque},
+  vec::Vec,
+};
+
+/// See [`push_front`](PushFront::push_front) for more information.
+pub trait PushFront {
+  /// Error
+  type Error;
+  /// Input
+  type Input;
+  /// Ok
+  type Ok;
+
+  /// Pushes an element at the beginning of the storage, increasing its length.
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error>;
+}
+
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// let mut structure = cl_traits::doc_tests::vec();
+/// cl_traits::PushFront::push_front(&mut &mut structure, 20);
+/// assert_eq!(structure.get(0), Some(&20));
+/// # }
+/// ```
+impl<T> PushFront for &'_ mut T
+where
+  T: PushFront + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).push_front(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::PushFront::push_front(&mut structure, 20);
+/// assert_eq!(structure.get(0), Some(&20));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PushFront for Box<T>
+where
+  T: PushFront + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).push_front(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::linked_list();
+/// cl_traits::PushFront::push_front(&mut structure, 20);
+/// assert_eq!(structure.front(), Some(&20));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PushFront for LinkedList<T> {
+  type Error = core::convert::Infallible;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.push_front(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::PushFront::push_front(&mut structure, 20);
+/// assert_eq!(structure.front(), Some(&20));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PushFront for VecDeque<T> {
+  type Error = core::convert::Infallible;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.push_front(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::PushFront::push_front(&mut structure, 20);
+/// assert_eq!(structure.get(0), Some(&20));
+/// ```
+impl<T, const N: usize> PushFront for crate::ArrayStorage<T, N> {
+  type Error = crate::InsertError<T>;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    if self.is_full() {
+      return Err(crate::InsertError::new(
+        input,
+        crate::InsertErrorReason::CapacityExceeded { capacity: N },
+      ));
+    }
+    self.insert(0, input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// cl_traits::PushFront::push_front(&mut structure, 20);
+/// assert_eq!(structure.get(0), Some(&20));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PushFront for Vec<T> {
+  type Error = core::convert::Infallible;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.insert(0, input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// cl_traits::PushFront::push_front(&mut structure, 20);
+/// assert_eq!(structure.get(0), Some(&20));
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> PushFront for arrayvec::ArrayVec<T, N> {
+  type Error = crate::InsertError<T>;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.try_insert(0, input).map_err(|e| {
+      crate::InsertError::new(
+        e.element(),
+        crate::InsertErrorReason::CapacityExceeded { capacity: N },
+      )
+    })
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// cl_traits::PushFront::push_front(&mut structure, 20);
+/// assert_eq!(structure.get(0), Some(&20));
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> PushFront for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Error = core::convert::Infallible;
+  type Input = A::Item;
+  type Ok = ();
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.insert(0, input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// cl_traits::PushFront::push_front(&mut structure, 20);
+/// assert_eq!(structure.get(0), Some(&20));
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> PushFront for staticvec::StaticVec<T, N> {
+  type Error = crate::InsertError<T>;
+  type Input = T;
+  type Ok = ();
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    if self.is_full() {
+      return Err(crate::InsertError::new(
+        input,
+        crate::InsertErrorReason::CapacityExceeded { capacity: N },
+      ));
+    }
+    self.insert(0, input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// cl_traits::PushFront::push_front(&mut structure, 20);
+/// assert_eq!(structure.get(0), Some(&20));
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> PushFront for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = crate::InsertError<A::Item>;
+  type Input = A::Item;
+  type Ok = ();
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    match self.try_insert(0, input) {
+      None => Ok(()),
+      Some(rslt) => Err(crate::InsertError::new(
+        rslt,
+        crate::InsertErrorReason::CapacityExceeded { capacity: A::CAPACITY },
+      )),
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// cl_traits::PushFront::push_front(&mut structure, 20);
+/// assert_eq!(structure.get(0), Some(&20));
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> PushFront for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = core::convert::Infallible;
+  type Input = A::Item;
+  type Ok = ();
+
+  #[inline]
+  fn push_front(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.insert(0, input);
+    Ok(())
+  }
+}
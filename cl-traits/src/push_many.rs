@@ -0,0 +1,452 @@
+use crate::Storage;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use core::{fmt, iter::Peekable};
+
+#[allow(
+  // For convenience because of selected features
+  unused
+)]
+// Manages fixed-capacity vectors whose `Extend` implementations panic or silently discard
+// elements once full
+macro_rules! manage_fixed_iter {
+  ($v:expr, $input:expr, $remaining_capacity:expr) => {{
+    let len = $v.len();
+    let mut iter = $input.into_iter().peekable();
+    $v.extend(iter.by_ref().take($remaining_capacity));
+    if iter.peek().is_none() {
+      Ok(())
+    } else {
+      Err(PushManyError { pushed: $v.len().wrapping_sub(len), remainder: iter })
+    }
+  }};
+}
+
+#[allow(
+  // For convenience because of selected features
+  unused
+)]
+// Splits `$input` into what fits and what doesn't, extending `$v` with the former through `$cb`
+macro_rules! manage_fixed_slice {
+  ($v:expr, $input:expr, $remaining_capacity:expr, $cb:expr) => {{
+    let (head, tail) = $input.split_at($input.len().min($remaining_capacity));
+    $cb($v, head);
+    if tail.is_empty() {
+      Ok(())
+    } else {
+      Err(PushManyError { pushed: head.len(), remainder: tail })
+    }
+  }};
+}
+
+/// Error of [`PushMany`] when the storage is filled before all elements are pushed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PushManyError<R> {
+  /// Number of elements that were successfully pushed.
+  pub pushed: usize,
+  /// Elements that weren't pushed.
+  pub remainder: R,
+}
+
+impl<R> fmt::Display for PushManyError<R> {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "storage became full after pushing {} elements", self.pushed)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<R> std::error::Error for PushManyError<R> where R: fmt::Debug {}
+
+/// See [`push_many`](PushMany::push_many) and [`push_slice`](PushMany::push_slice) for more
+/// information.
+pub trait PushMany: Storage {
+  /// Pushes all elements yielded by `input`, increasing the storage length.
+  ///
+  /// If the storage becomes full, the returned error holds the iterator with the elements that
+  /// weren't pushed.
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>;
+
+  /// Pushes clones of all elements of `input`, increasing the storage length.
+  ///
+  /// If the storage becomes full, the returned error holds the sub-slice with the elements that
+  /// weren't pushed.
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone;
+}
+
+/// ```rust
+/// let mut structure = None;
+/// assert!(cl_traits::PushMany::push_slice(&mut &mut structure, &[1]).is_ok());
+/// assert_eq!(structure, Some(1));
+/// ```
+impl<T> PushMany for &'_ mut T
+where
+  T: PushMany + ?Sized,
+{
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    (**self).push_many(input)
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    (**self).push_slice(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// assert!(cl_traits::PushMany::push_many(&mut structure, [4, 5, 6].iter().copied()).is_ok());
+/// assert_eq!(structure.len(), 6);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PushMany for Box<T>
+where
+  T: PushMany + ?Sized,
+{
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    (**self).push_many(input)
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    (**self).push_slice(input)
+  }
+}
+
+/// ```rust
+/// let mut opt = None;
+/// assert!(cl_traits::PushMany::push_many(&mut opt, [1].iter().copied()).is_ok());
+/// let err = cl_traits::PushMany::push_slice(&mut opt, &[2, 3]).unwrap_err();
+/// assert_eq!((err.pushed, err.remainder), (0, &[2, 3][..]));
+/// assert_eq!(opt, Some(1));
+/// ```
+impl<T> PushMany for Option<T> {
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    let mut iter = input.into_iter().peekable();
+    let mut pushed = 0;
+    if self.is_none() {
+      if let Some(elem) = iter.next() {
+        *self = Some(elem);
+        pushed = 1;
+      }
+    }
+    if iter.peek().is_none() {
+      Ok(())
+    } else {
+      Err(PushManyError { pushed, remainder: iter })
+    }
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    let remaining_capacity = if self.is_none() { 1 } else { 0 };
+    manage_fixed_slice!(self, input, remaining_capacity, |this: &mut Self, head: &[T]| {
+      if let Some(elem) = head.first() {
+        *this = Some(elem.clone());
+      }
+    })
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// let mut err = cl_traits::PushMany::push_many(&mut structure, 4..10).unwrap_err();
+/// assert_eq!(err.pushed, 2);
+/// assert_eq!(err.remainder.next(), Some(6));
+/// let err = cl_traits::PushMany::push_slice(&mut structure, &[10]).unwrap_err();
+/// assert_eq!((err.pushed, err.remainder), (0, &[10][..]));
+/// assert_eq!(&structure[..], &[1, 2, 3, 4, 5]);
+/// ```
+impl<T, const N: usize> PushMany for crate::ArrayStorage<T, N> {
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    let remaining_capacity = self.remaining_capacity();
+    manage_fixed_iter!(self, input, remaining_capacity)
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    let remaining_capacity = self.remaining_capacity();
+    manage_fixed_slice!(self, input, remaining_capacity, |this: &mut Self, head: &[T]| this
+      .extend(head.iter().cloned()))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// assert!(cl_traits::PushMany::push_many(&mut structure, [4, 5, 6].iter().copied()).is_ok());
+/// assert!(cl_traits::PushMany::push_slice(&mut structure, &[7, 8]).is_ok());
+/// assert_eq!(structure.len(), 8);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PushMany for Vec<T> {
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    self.extend(input);
+    Ok(())
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    self.extend_from_slice(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// assert!(cl_traits::PushMany::push_many(&mut structure, [4, 5, 6].iter().copied()).is_ok());
+/// assert!(cl_traits::PushMany::push_slice(&mut structure, &[7, 8]).is_ok());
+/// assert_eq!(structure.len(), 8);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PushMany for VecDeque<T> {
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    self.extend(input);
+    Ok(())
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    self.extend(input.iter().cloned());
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// let err = cl_traits::PushMany::push_many(&mut structure, 4..10).unwrap_err();
+/// assert_eq!(err.pushed, 2);
+/// assert_eq!(err.remainder.collect::<Vec<_>>(), [6, 7, 8, 9]);
+/// let err = cl_traits::PushMany::push_slice(&mut structure, &[10]).unwrap_err();
+/// assert_eq!((err.pushed, err.remainder), (0, &[10][..]));
+/// assert_eq!(&structure[..], &[1, 2, 3, 4, 5]);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> PushMany for arrayvec::ArrayVec<T, N> {
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    let remaining_capacity = self.remaining_capacity();
+    manage_fixed_iter!(self, input, remaining_capacity)
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    let remaining_capacity = self.remaining_capacity();
+    manage_fixed_slice!(self, input, remaining_capacity, |this: &mut Self, head: &[T]| this
+      .extend(head.iter().cloned()))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// assert!(cl_traits::PushMany::push_many(&mut structure, [4, 5, 6].iter().copied()).is_ok());
+/// assert!(cl_traits::PushMany::push_slice(&mut structure, &[7, 8]).is_ok());
+/// assert_eq!(structure.len(), 8);
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> PushMany for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    self.extend(input);
+    Ok(())
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    self.extend(input.iter().cloned());
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// let err = cl_traits::PushMany::push_many(&mut structure, 4..10).unwrap_err();
+/// assert_eq!(err.pushed, 2);
+/// assert_eq!(err.remainder.collect::<Vec<_>>(), [6, 7, 8, 9]);
+/// let err = cl_traits::PushMany::push_slice(&mut structure, &[10]).unwrap_err();
+/// assert_eq!((err.pushed, err.remainder), (0, &[10][..]));
+/// assert_eq!(&structure[..], &[1, 2, 3, 4, 5]);
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> PushMany for staticvec::StaticVec<T, N> {
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    let remaining_capacity = self.remaining_capacity();
+    manage_fixed_iter!(self, input, remaining_capacity)
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    let remaining_capacity = self.remaining_capacity();
+    manage_fixed_slice!(self, input, remaining_capacity, |this: &mut Self, head: &[T]| this
+      .extend(head.iter().cloned()))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// let err = cl_traits::PushMany::push_many(&mut structure, 4..10).unwrap_err();
+/// assert_eq!(err.pushed, 2);
+/// assert_eq!(err.remainder.collect::<Vec<_>>(), [6, 7, 8, 9]);
+/// let err = cl_traits::PushMany::push_slice(&mut structure, &[10]).unwrap_err();
+/// assert_eq!((err.pushed, err.remainder), (0, &[10][..]));
+/// assert_eq!(&structure[..], &[1, 2, 3, 4, 5]);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> PushMany for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    let remaining_capacity = A::CAPACITY.saturating_sub(self.len());
+    manage_fixed_iter!(self, input, remaining_capacity)
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    let remaining_capacity = A::CAPACITY.saturating_sub(self.len());
+    manage_fixed_slice!(self, input, remaining_capacity, |this: &mut Self, head: &[A::Item]| this
+      .extend_from_slice(head))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// assert!(cl_traits::PushMany::push_many(&mut structure, [4, 5, 6].iter().copied()).is_ok());
+/// assert!(cl_traits::PushMany::push_slice(&mut structure, &[7, 8]).is_ok());
+/// assert_eq!(structure.len(), 8);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> PushMany for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  #[inline]
+  fn push_many<I>(&mut self, input: I) -> Result<(), PushManyError<Peekable<I::IntoIter>>>
+  where
+    I: IntoIterator<Item = Self::Item>,
+  {
+    self.extend(input);
+    Ok(())
+  }
+
+  #[inline]
+  fn push_slice<'input>(
+    &mut self,
+    input: &'input [Self::Item],
+  ) -> Result<(), PushManyError<&'input [Self::Item]>>
+  where
+    Self::Item: Clone,
+  {
+    self.extend_from_slice(input);
+    Ok(())
+  }
+}
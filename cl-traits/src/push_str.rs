@@ -0,0 +1,152 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String};
+
+/// See [`push_str`](PushStr::push_str) for more information.
+pub trait PushStr {
+  /// Error
+  type Error<'input>;
+  /// Ok
+  type Ok;
+
+  /// Appends a string slice to the end of the text buffer, increasing its length.
+  ///
+  /// Either all of `input` is appended or nothing is.
+  fn push_str<'input>(&mut self, input: &'input str) -> Result<Self::Ok, Self::Error<'input>>;
+}
+
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// let mut structure = String::from("ab");
+/// cl_traits::PushStr::push_str(&mut &mut structure, "c");
+/// assert_eq!(structure, "abc");
+/// # }
+/// ```
+impl<T> PushStr for &'_ mut T
+where
+  T: PushStr + ?Sized,
+{
+  type Error<'input> = T::Error<'input>;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn push_str<'input>(&mut self, input: &'input str) -> Result<Self::Ok, Self::Error<'input>> {
+    (**self).push_str(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(String::from("ab"));
+/// cl_traits::PushStr::push_str(&mut structure, "c");
+/// assert_eq!(structure.as_str(), "abc");
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> PushStr for Box<T>
+where
+  T: PushStr + ?Sized,
+{
+  type Error<'input> = T::Error<'input>;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn push_str<'input>(&mut self, input: &'input str) -> Result<Self::Ok, Self::Error<'input>> {
+    (**self).push_str(input)
+  }
+}
+
+/// ```rust
+/// let mut structure: Box<str> = "ab".into();
+/// cl_traits::PushStr::push_str(&mut structure, "c");
+/// assert_eq!(&*structure, "abc");
+/// ```
+#[cfg(feature = "alloc")]
+impl PushStr for Box<str> {
+  type Error<'input> = core::convert::Infallible;
+  type Ok = ();
+
+  #[inline]
+  fn push_str<'input>(&mut self, input: &'input str) -> Result<Self::Ok, Self::Error<'input>> {
+    let mut string = String::from(core::mem::take(self));
+    string.push_str(input);
+    *self = string.into_boxed_str();
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::string();
+/// cl_traits::PushStr::push_str(&mut structure, "de");
+/// assert_eq!(structure, "abcde");
+/// ```
+#[cfg(feature = "alloc")]
+impl PushStr for String {
+  type Error<'input> = core::convert::Infallible;
+  type Ok = ();
+
+  #[inline]
+  fn push_str<'input>(&mut self, input: &'input str) -> Result<Self::Ok, Self::Error<'input>> {
+    self.push_str(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_string();
+/// cl_traits::PushStr::push_str(&mut structure, "de");
+/// assert_eq!(structure.as_str(), "abcde");
+/// let err = cl_traits::PushStr::push_str(&mut structure, "fgh").unwrap_err();
+/// assert_eq!(err.element, "fgh");
+/// assert_eq!(structure.as_str(), "abcde");
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<const CAP: usize> PushStr for arrayvec::ArrayString<CAP> {
+  type Error<'input> = crate::InsertError<&'input str>;
+  type Ok = ();
+
+  #[inline]
+  fn push_str<'input>(&mut self, input: &'input str) -> Result<Self::Ok, Self::Error<'input>> {
+    self.try_push_str(input).map_err(|e| {
+      crate::InsertError::new(
+        e.element(),
+        crate::InsertErrorReason::CapacityExceeded { capacity: CAP },
+      )
+    })
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_string();
+/// cl_traits::PushStr::push_str(&mut structure, "de");
+/// assert_eq!(structure.as_str(), "abcde");
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> PushStr for heapless::String<N> {
+  type Error<'input> = crate::InsertError<&'input str>;
+  type Ok = ();
+
+  #[inline]
+  fn push_str<'input>(&mut self, input: &'input str) -> Result<Self::Ok, Self::Error<'input>> {
+    self.push_str(input).map_err(|_| {
+      crate::InsertError::new(input, crate::InsertErrorReason::CapacityExceeded { capacity: N })
+    })
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_string();
+/// cl_traits::PushStr::push_str(&mut structure, "de");
+/// assert_eq!(structure.as_str(), "abcde");
+/// ```
+#[cfg(feature = "with-smallstr")]
+impl<A> PushStr for smallstr::SmallString<A>
+where
+  A: smallvec::Array<Item = u8>,
+{
+  type Error<'input> = core::convert::Infallible;
+  type Ok = ();
+
+  #[inline]
+  fn push_str<'input>(&mut self, input: &'input str) -> Result<Self::Ok, Self::Error<'input>> {
+    self.push_str(input);
+    Ok(())
+  }
+}
@@ -1,5 +1,12 @@
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, BTreeSet},
+  vec::Vec,
+};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 #[allow(
   // For convenience because of selected features
@@ -8,40 +15,289 @@ use alloc::vec::Vec;
 // Manages vectors that don't perform bound checks
 macro_rules! manage_vec {
   ($v:expr, $idx:expr) => {{
-    if $idx >= $v.len() {
-      return Err(());
+    let len = $v.len();
+    if $idx >= len {
+      return Err(OutOfBounds { index: $idx, len });
     }
     Ok($v.remove($idx))
   }};
 }
 
+/// Error of [`Remove`] when the referenced key or element isn't stored.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KeyNotFound;
+
+impl fmt::Display for KeyNotFound {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "key not found")
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyNotFound {}
+
+/// Error of [`Remove`] when the referenced index is greater than or equal to the length.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OutOfBounds {
+  /// Provided index
+  pub index: usize,
+  /// Length of the collection
+  pub len: usize,
+}
+
+impl fmt::Display for OutOfBounds {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "index {} is out of bounds for a length of {}", self.index, self.len)
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBounds {}
+
+/// Error of [`Remove`] for strings, whose indices are bytes that must lie on a `char` boundary.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RemoveCharError {
+  /// See [`CharBoundaryError`](crate::CharBoundaryError).
+  CharBoundary(crate::CharBoundaryError),
+  /// See [`OutOfBounds`].
+  OutOfBounds(OutOfBounds),
+}
+
+impl fmt::Display for RemoveCharError {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match *self {
+      Self::CharBoundary(ref elem) => elem.fmt(f),
+      Self::OutOfBounds(ref elem) => elem.fmt(f),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RemoveCharError {}
+
 /// See [`remove`](Remove::remove) for more information.
 pub trait Remove {
   /// Error
   type Error;
   /// Input
-  type Input;
+  type Input<'input>
+  where
+    Self: 'input;
   /// Ok
   type Ok;
 
   /// Removes an element referenced by `Input`.
-  fn remove(&mut self, idx: Self::Input) -> Result<Self::Ok, Self::Error>;
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input;
 }
 
 /// ```rust
+/// # #[cfg(feature = "alloc")] {
 /// let mut structure = cl_traits::doc_tests::vec();
+/// assert_eq!(cl_traits::Remove::remove(&mut &mut structure, 0), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// # }
+/// ```
+impl<T> Remove for &'_ mut T
+where
+  T: Remove + ?Sized,
+{
+  type Error = T::Error;
+  type Input<'input>
+    = T::Input<'input>
+  where
+    Self: 'input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    (**self).remove(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, 0), Ok(1));
+/// assert_eq!(structure.get(0), Some(&2));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Remove for Box<T>
+where
+  T: Remove + ?Sized,
+{
+  type Error = T::Error;
+  type Input<'input>
+    = T::Input<'input>
+  where
+    Self: 'input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    (**self).remove(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::b_tree_map();
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &0), Ok(1));
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &0), Err(cl_traits::KeyNotFound));
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> Remove for BTreeMap<K, V>
+where
+  K: Ord,
+{
+  type Error = KeyNotFound;
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok = V;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.remove(input).ok_or(KeyNotFound)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::b_tree_set();
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &1), Ok(1));
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &1), Err(cl_traits::KeyNotFound));
+/// ```
+#[cfg(feature = "alloc")]
+impl<V> Remove for BTreeSet<V>
+where
+  V: Ord,
+{
+  type Error = KeyNotFound;
+  type Input<'input>
+    = &'input V
+  where
+    Self: 'input;
+  type Ok = V;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.take(input).ok_or(KeyNotFound)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_map();
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &0), Ok(1));
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &0), Err(cl_traits::KeyNotFound));
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> Remove for HashMap<K, V>
+where
+  K: Eq + core::hash::Hash,
+{
+  type Error = KeyNotFound;
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok = V;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.remove(input).ok_or(KeyNotFound)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_set();
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &1), Ok(1));
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &1), Err(cl_traits::KeyNotFound));
+/// ```
+#[cfg(feature = "std")]
+impl<V> Remove for HashSet<V>
+where
+  V: Eq + core::hash::Hash,
+{
+  type Error = KeyNotFound;
+  type Input<'input>
+    = &'input V
+  where
+    Self: 'input;
+  type Ok = V;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.take(input).ok_or(KeyNotFound)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
 /// cl_traits::Remove::remove(&mut structure, 0);
 /// assert_eq!(structure.get(0), Some(&2));
 /// ```
+impl<T, const N: usize> Remove for crate::ArrayStorage<T, N> {
+  type Error = OutOfBounds;
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok = T;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    manage_vec!(self, input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// cl_traits::Remove::remove(&mut structure, 0);
+/// assert_eq!(structure.get(0), Some(&2));
+/// assert_eq!(
+///   cl_traits::Remove::remove(&mut structure, 2),
+///   Err(cl_traits::OutOfBounds { index: 2, len: 2 })
+/// );
+/// ```
 #[cfg(feature = "alloc")]
 impl<T> Remove for Vec<T> {
-  type Error = ();
-  type Input = usize;
+  type Error = OutOfBounds;
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
   type Ok = T;
 
   #[inline]
-  fn remove(&mut self, idx: Self::Input) -> Result<Self::Ok, Self::Error> {
-    manage_vec!(self, idx)
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    manage_vec!(self, input)
   }
 }
 
@@ -52,13 +308,247 @@ impl<T> Remove for Vec<T> {
 /// ```
 #[cfg(feature = "with-arrayvec")]
 impl<T, const N: usize> Remove for arrayvec::ArrayVec<T, N> {
-  type Error = ();
-  type Input = usize;
+  type Error = OutOfBounds;
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok = T;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    manage_vec!(self, input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &0), Ok(1));
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &0), Err(cl_traits::KeyNotFound));
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> Remove for hashbrown::HashMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = KeyNotFound;
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok = V;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.remove(input).ok_or(KeyNotFound)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_set();
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &1), Ok(1));
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &1), Err(cl_traits::KeyNotFound));
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> Remove for hashbrown::HashSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = KeyNotFound;
+  type Input<'input>
+    = &'input V
+  where
+    Self: 'input;
+  type Ok = V;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.take(input).ok_or(KeyNotFound)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_deque();
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, 0), Ok(1));
+/// assert_eq!(structure.front(), Some(&2));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Remove for heapless::Deque<T, N> {
+  type Error = OutOfBounds;
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok = T;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    let len = self.len();
+    if input >= len {
+      return Err(OutOfBounds { index: input, len });
+    }
+    for idx in input..len.wrapping_sub(1) {
+      let _ = crate::Swap::swap(self, [idx, idx.wrapping_add(1)]);
+    }
+    self.pop_back().ok_or(OutOfBounds { index: input, len })
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_index_map();
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &0), Ok(1));
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &0), Err(cl_traits::KeyNotFound));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> Remove for heapless::IndexMap<K, V, S, N>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = KeyNotFound;
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok = V;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.remove(input).ok_or(KeyNotFound)
+  }
+}
+
+/// ```rust
+/// use cl_traits::{CharBoundaryError, OutOfBounds, Remove, RemoveCharError};
+/// let mut structure = cl_traits::doc_tests::heapless_string();
+/// assert_eq!(Remove::remove(&mut structure, 0), Ok('a'));
+/// assert_eq!(structure.as_str(), "bc");
+/// assert_eq!(
+///   Remove::remove(&mut structure, 2),
+///   Err(RemoveCharError::OutOfBounds(OutOfBounds { index: 2, len: 2 }))
+/// );
+/// structure.push('é').unwrap();
+/// assert_eq!(
+///   Remove::remove(&mut structure, 3),
+///   Err(RemoveCharError::CharBoundary(CharBoundaryError { index: 3 }))
+/// );
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> Remove for heapless::String<N> {
+  type Error = RemoveCharError;
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
+  type Ok = char;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    let len = self.len();
+    if input >= len {
+      return Err(RemoveCharError::OutOfBounds(OutOfBounds { index: input, len }));
+    }
+    if !self.is_char_boundary(input) {
+      return Err(RemoveCharError::CharBoundary(crate::CharBoundaryError { index: input }));
+    }
+    Ok(self.remove(input))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_vec();
+/// cl_traits::Remove::remove(&mut structure, 0);
+/// assert_eq!(structure.get(0), Some(&2));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Remove for heapless::Vec<T, N> {
+  type Error = OutOfBounds;
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
   type Ok = T;
 
   #[inline]
-  fn remove(&mut self, idx: Self::Input) -> Result<Self::Ok, Self::Error> {
-    manage_vec!(self, idx)
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    manage_vec!(self, input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &0), Ok(1));
+/// assert_eq!(structure.get_index(0), Some((&1, &2)));
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Remove for indexmap::IndexMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = KeyNotFound;
+  type Input<'input>
+    = &'input K
+  where
+    Self: 'input;
+  type Ok = V;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.shift_remove(input).ok_or(KeyNotFound)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_set();
+/// assert_eq!(cl_traits::Remove::remove(&mut structure, &1), Ok(1));
+/// assert_eq!(structure.get_index(0), Some(&2));
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Remove for indexmap::IndexSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Error = KeyNotFound;
+  type Input<'input>
+    = &'input V
+  where
+    Self: 'input;
+  type Ok = V;
+
+  #[inline]
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    self.shift_take(input).ok_or(KeyNotFound)
   }
 }
 
@@ -72,13 +562,19 @@ impl<A> Remove for smallvec::SmallVec<A>
 where
   A: smallvec::Array,
 {
-  type Error = ();
-  type Input = usize;
+  type Error = OutOfBounds;
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
   type Ok = A::Item;
 
   #[inline]
-  fn remove(&mut self, idx: Self::Input) -> Result<Self::Ok, Self::Error> {
-    manage_vec!(self, idx)
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    manage_vec!(self, input)
   }
 }
 
@@ -89,13 +585,19 @@ where
 /// ```
 #[cfg(feature = "with-staticvec")]
 impl<T, const N: usize> Remove for staticvec::StaticVec<T, N> {
-  type Error = ();
-  type Input = usize;
+  type Error = OutOfBounds;
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
   type Ok = T;
 
   #[inline]
-  fn remove(&mut self, idx: Self::Input) -> Result<Self::Ok, Self::Error> {
-    manage_vec!(self, idx)
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    manage_vec!(self, input)
   }
 }
 
@@ -110,13 +612,19 @@ where
   A: tinyvec::Array,
   A::Item: Default,
 {
-  type Error = ();
-  type Input = usize;
+  type Error = OutOfBounds;
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
   type Ok = A::Item;
 
   #[inline]
-  fn remove(&mut self, idx: Self::Input) -> Result<Self::Ok, Self::Error> {
-    manage_vec!(self, idx)
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    manage_vec!(self, input)
   }
 }
 
@@ -131,12 +639,18 @@ where
   A: tinyvec::Array,
   A::Item: Default,
 {
-  type Error = ();
-  type Input = usize;
+  type Error = OutOfBounds;
+  type Input<'input>
+    = usize
+  where
+    Self: 'input;
   type Ok = A::Item;
 
   #[inline]
-  fn remove(&mut self, idx: Self::Input) -> Result<Self::Ok, Self::Error> {
-    manage_vec!(self, idx)
+  fn remove<'input>(&mut self, input: Self::Input<'input>) -> Result<Self::Ok, Self::Error>
+  where
+    Self: 'input,
+  {
+    manage_vec!(self, input)
   }
 }
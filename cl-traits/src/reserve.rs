@@ -0,0 +1,271 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use core::fmt;
+
+/// Error of [`Reserve`], [`ReserveExact`](crate::ReserveExact) and
+/// [`TryReserve`](crate::TryReserve).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReserveError {
+  /// The allocator couldn't provide the requested memory.
+  AllocError,
+  /// The requested capacity is greater than what
+  /// [`CapacityUpperBound`](crate::CapacityUpperBound) allows.
+  CapacityUpperBoundExceeded {
+    /// See [`CapacityUpperBound`](crate::CapacityUpperBound).
+    capacity_upper_bound: usize,
+    /// Current length plus the additional number of elements.
+    requested: usize,
+  },
+}
+
+impl fmt::Display for ReserveError {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match *self {
+      Self::AllocError => write!(f, "memory allocation failed"),
+      Self::CapacityUpperBoundExceeded { capacity_upper_bound, requested } => write!(
+        f,
+        "requested capacity of {} exceeds the upper bound of {}",
+        requested, capacity_upper_bound
+      ),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReserveError {}
+
+/// See [`reserve`](Reserve::reserve) for more information.
+pub trait Reserve {
+  /// Error
+  type Error;
+  /// Input
+  type Input;
+  /// Ok
+  type Ok;
+
+  /// Reserves capacity for at least `Input` more elements.
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error>;
+}
+
+/// ```rust
+/// let mut structure = None::<i32>;
+/// assert!(cl_traits::Reserve::reserve(&mut &mut structure, 1).is_ok());
+/// ```
+impl<T> Reserve for &'_ mut T
+where
+  T: Reserve + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).reserve(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::Reserve::reserve(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Reserve for Box<T>
+where
+  T: Reserve + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).reserve(input)
+  }
+}
+
+/// ```rust
+/// let mut opt = None::<i32>;
+/// assert!(cl_traits::Reserve::reserve(&mut opt, 1).is_ok());
+/// assert!(cl_traits::Reserve::reserve(&mut opt, 2).is_err());
+/// ```
+impl<T> Reserve for Option<T> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(crate::Length::length(self), input, 1)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// assert!(cl_traits::Reserve::reserve(&mut structure, 2).is_ok());
+/// assert!(cl_traits::Reserve::reserve(&mut structure, 3).is_err());
+/// ```
+impl<T, const N: usize> Reserve for crate::ArrayStorage<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, N)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// cl_traits::Reserve::reserve(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Reserve for Vec<T> {
+  type Error = core::convert::Infallible;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.reserve(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::Reserve::reserve(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Reserve for VecDeque<T> {
+  type Error = core::convert::Infallible;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.reserve(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// cl_traits::Reserve::reserve(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> Reserve for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Error = core::convert::Infallible;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.reserve(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// cl_traits::Reserve::reserve(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> Reserve for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = core::convert::Infallible;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.reserve(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// assert!(cl_traits::Reserve::reserve(&mut structure, 2).is_ok());
+/// assert!(cl_traits::Reserve::reserve(&mut structure, 3).is_err());
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> Reserve for arrayvec::ArrayVec<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, N)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// assert!(cl_traits::Reserve::reserve(&mut structure, 2).is_ok());
+/// assert!(cl_traits::Reserve::reserve(&mut structure, 3).is_err());
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> Reserve for staticvec::StaticVec<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, N)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// assert!(cl_traits::Reserve::reserve(&mut structure, 2).is_ok());
+/// assert!(cl_traits::Reserve::reserve(&mut structure, 3).is_err());
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> Reserve for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, A::CAPACITY)
+  }
+}
+
+#[allow(
+  // For convenience because of selected features
+  unused
+)]
+#[inline]
+pub(crate) fn reserve_fixed(
+  len: usize,
+  additional: usize,
+  capacity_upper_bound: usize,
+) -> Result<(), ReserveError> {
+  let requested = len.saturating_add(additional);
+  if requested > capacity_upper_bound {
+    Err(ReserveError::CapacityUpperBoundExceeded { capacity_upper_bound, requested })
+  } else {
+    Ok(())
+  }
+}
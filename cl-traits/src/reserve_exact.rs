@@ -0,0 +1,220 @@
+use crate::{reserve::reserve_fixed, ReserveError};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+
+/// See [`reserve_exact`](ReserveExact::reserve_exact) for more information.
+pub trait ReserveExact {
+  /// Error
+  type Error;
+  /// Input
+  type Input;
+  /// Ok
+  type Ok;
+
+  /// Reserves the minimum capacity for exactly `Input` more elements.
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error>;
+}
+
+/// ```rust
+/// let mut structure = None::<i32>;
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut &mut structure, 1).is_ok());
+/// ```
+impl<T> ReserveExact for &'_ mut T
+where
+  T: ReserveExact + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).reserve_exact(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::ReserveExact::reserve_exact(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> ReserveExact for Box<T>
+where
+  T: ReserveExact + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).reserve_exact(input)
+  }
+}
+
+/// ```rust
+/// let mut opt = None::<i32>;
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut opt, 1).is_ok());
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut opt, 2).is_err());
+/// ```
+impl<T> ReserveExact for Option<T> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(crate::Length::length(self), input, 1)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut structure, 2).is_ok());
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut structure, 3).is_err());
+/// ```
+impl<T, const N: usize> ReserveExact for crate::ArrayStorage<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, N)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// cl_traits::ReserveExact::reserve_exact(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> ReserveExact for Vec<T> {
+  type Error = core::convert::Infallible;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.reserve_exact(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::ReserveExact::reserve_exact(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> ReserveExact for VecDeque<T> {
+  type Error = core::convert::Infallible;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.reserve_exact(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// cl_traits::ReserveExact::reserve_exact(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> ReserveExact for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Error = core::convert::Infallible;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.reserve_exact(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// cl_traits::ReserveExact::reserve_exact(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> ReserveExact for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = core::convert::Infallible;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    self.reserve_exact(input);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut structure, 2).is_ok());
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut structure, 3).is_err());
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> ReserveExact for arrayvec::ArrayVec<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, N)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut structure, 2).is_ok());
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut structure, 3).is_err());
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> ReserveExact for staticvec::StaticVec<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, N)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut structure, 2).is_ok());
+/// assert!(cl_traits::ReserveExact::reserve_exact(&mut structure, 3).is_err());
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> ReserveExact for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn reserve_exact(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, A::CAPACITY)
+  }
+}
@@ -1,15 +1,167 @@
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, BTreeSet, VecDeque},
+  vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// See [`retain`](Retain::retain) for more information.
+///
+/// Maps provide their values, see [`RetainMap`](crate::RetainMap) for predicates that also
+/// receive keys.
 pub trait Retain {
-  /// Input
+  /// Predicate input
   type Input;
   /// Output
   type Output;
 
   /// Retains only the elements specified by the `F` predicate.
-  fn retain(&mut self, input: Self::Input) -> Self::Output;
+  fn retain<F>(&mut self, input: F) -> Self::Output
+  where
+    F: FnMut(&Self::Input) -> bool;
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::Retain::retain(&mut &mut structure, |n| *n >= 2);
+/// assert_eq!(&structure[..], &[2, 3]);
+/// ```
+impl<T> Retain for &'_ mut T
+where
+  T: Retain + ?Sized,
+{
+  type Input = T::Input;
+  type Output = T::Output;
+
+  #[inline]
+  fn retain<F>(&mut self, input: F) -> Self::Output
+  where
+    F: FnMut(&T::Input) -> bool,
+  {
+    (**self).retain(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::Retain::retain(&mut structure, |n| *n >= 2);
+/// assert_eq!(&structure[..], &[2, 3]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Retain for Box<T>
+where
+  T: Retain + ?Sized,
+{
+  type Input = T::Input;
+  type Output = T::Output;
+
+  #[inline]
+  fn retain<F>(&mut self, input: F) -> Self::Output
+  where
+    F: FnMut(&T::Input) -> bool,
+  {
+    (**self).retain(input)
+  }
+}
+
+/// ```rust
+/// use cl_traits::{Clear, Length, Retain};
+///
+/// fn prune<S>(structure: &mut S) -> usize
+/// where
+///   S: Clear + Length + Retain<Input = i32>,
+/// {
+///   structure.retain(|v| *v >= 2);
+///   structure.length()
+/// }
+///
+/// assert_eq!(prune(&mut cl_traits::doc_tests::b_tree_map()), 2);
+/// assert_eq!(prune(&mut cl_traits::doc_tests::b_tree_set()), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> Retain for BTreeMap<K, V>
+where
+  K: Ord,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&V) -> bool,
+  {
+    self.retain(|_, v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::b_tree_set();
+/// cl_traits::Retain::retain(&mut structure, |n| n % 2 == 0);
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "alloc")]
+impl<V> Retain for BTreeSet<V>
+where
+  V: Ord,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&V) -> bool,
+  {
+    self.retain(|v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_map();
+/// let threshold = 2;
+/// cl_traits::Retain::retain(&mut structure, |v| *v >= threshold);
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> Retain for HashMap<K, V>
+where
+  K: Eq + core::hash::Hash,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&V) -> bool,
+  {
+    self.retain(|_, v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_set();
+/// cl_traits::Retain::retain(&mut structure, |n| n % 2 == 0);
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "std")]
+impl<V> Retain for HashSet<V>
+where
+  V: Eq + core::hash::Hash,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&V) -> bool,
+  {
+    self.retain(|v| input(v));
+  }
 }
 
 /// ```rust
@@ -18,12 +170,15 @@ pub trait Retain {
 /// assert_eq!(opt, None);
 /// ```
 impl<T> Retain for Option<T> {
-  type Input = fn(&T) -> bool;
+  type Input = T;
   type Output = ();
 
   #[inline]
-  fn retain(&mut self, input: Self::Input) {
-    if let Some(elem) = self.as_mut() {
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&T) -> bool,
+  {
+    if let Some(elem) = self.as_ref() {
       if !input(elem) {
         *self = None;
       }
@@ -32,18 +187,64 @@ impl<T> Retain for Option<T> {
 }
 
 /// ```rust
-/// let mut structure = cl_traits::doc_tests::vec();
+/// let mut structure = cl_traits::doc_tests::array_storage();
 /// cl_traits::Retain::retain(&mut structure, |n| n % 2 == 0);
-/// assert_eq!(&structure, &[2]);
+/// assert_eq!(&structure[..], &[2]);
+/// ```
+impl<T, const N: usize> Retain for crate::ArrayStorage<T, N> {
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&T) -> bool,
+  {
+    self.retain_mut(|elem| input(elem));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// let threshold = 2;
+/// cl_traits::Retain::retain(&mut structure, |n| *n >= threshold);
+/// assert_eq!(&structure, &[2, 3]);
+///
+/// let text = String::from("a bb ccc");
+/// let mut words: Vec<&str> = text.split(' ').collect();
+/// cl_traits::Retain::retain(&mut words, |word| word.len() > 1);
+/// assert_eq!(words, ["bb", "ccc"]);
 /// ```
 #[cfg(feature = "alloc")]
 impl<T> Retain for Vec<T> {
-  type Input = fn(&T) -> bool;
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&T) -> bool,
+  {
+    self.retain(|elem| input(elem));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::Retain::retain(&mut structure, |n| n % 2 == 0);
+/// assert_eq!(structure, [2]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Retain for VecDeque<T> {
+  type Input = T;
   type Output = ();
 
   #[inline]
-  fn retain(&mut self, input: Self::Input) {
-    self.retain(input);
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&T) -> bool,
+  {
+    self.retain(|elem| input(elem));
   }
 }
 
@@ -54,12 +255,201 @@ impl<T> Retain for Vec<T> {
 /// ```
 #[cfg(feature = "with-arrayvec")]
 impl<T, const N: usize> Retain for arrayvec::ArrayVec<T, N> {
-  type Input = fn(&T) -> bool;
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&T) -> bool,
+  {
+    self.retain(|elem| input(elem));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// let threshold = 2;
+/// cl_traits::Retain::retain(&mut structure, |v| *v >= threshold);
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> Retain for hashbrown::HashMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&V) -> bool,
+  {
+    self.retain(|_, v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_set();
+/// cl_traits::Retain::retain(&mut structure, |n| n % 2 == 0);
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> Retain for hashbrown::HashSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&V) -> bool,
+  {
+    self.retain(|v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_deque();
+/// cl_traits::Retain::retain(&mut structure, |n| n % 2 == 0);
+/// assert_eq!(structure.iter().copied().collect::<Vec<_>>(), [2]);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Retain for heapless::Deque<T, N> {
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&T) -> bool,
+  {
+    for _ in 0..self.len() {
+      if let Some(elem) = self.pop_front() {
+        if input(&elem) {
+          // Can't fail because an element was just popped
+          let _ = self.push_back(elem);
+        }
+      }
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_index_map();
+/// let threshold = 2;
+/// cl_traits::Retain::retain(&mut structure, |v| *v >= threshold);
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> Retain for heapless::IndexMap<K, V, S, N>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&V) -> bool,
+  {
+    self.retain(|_, v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_string();
+/// cl_traits::Retain::retain(&mut structure, |c| *c != 'b');
+/// assert_eq!(structure.as_str(), "ac");
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> Retain for heapless::String<N> {
+  type Input = char;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&char) -> bool,
+  {
+    let prev = core::mem::take(self);
+    for elem in prev.chars().filter(|elem| input(elem)) {
+      // Can't fail because `prev` had enough room for all characters
+      let _ = self.push(elem);
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_vec();
+/// cl_traits::Retain::retain(&mut structure, |n| n % 2 == 0);
+/// assert_eq!(&structure[..], &[2]);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Retain for heapless::Vec<T, N> {
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&T) -> bool,
+  {
+    self.retain(|elem| input(elem));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// let threshold = 2;
+/// cl_traits::Retain::retain(&mut structure, |v| *v >= threshold);
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Retain for indexmap::IndexMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&V) -> bool,
+  {
+    self.retain(|_, v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_set();
+/// cl_traits::Retain::retain(&mut structure, |n| n % 2 == 0);
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Retain for indexmap::IndexSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = V;
   type Output = ();
 
   #[inline]
-  fn retain(&mut self, input: Self::Input) {
-    self.retain(|i| input(i));
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&V) -> bool,
+  {
+    self.retain(|v| input(v));
   }
 }
 
@@ -73,12 +463,15 @@ impl<A> Retain for smallvec::SmallVec<A>
 where
   A: smallvec::Array,
 {
-  type Input = fn(&A::Item) -> bool;
+  type Input = A::Item;
   type Output = ();
 
   #[inline]
-  fn retain(&mut self, input: Self::Input) {
-    self.retain(|i| input(i));
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&A::Item) -> bool,
+  {
+    self.retain(|elem| input(elem));
   }
 }
 
@@ -89,12 +482,15 @@ where
 /// ```
 #[cfg(feature = "with-staticvec")]
 impl<T, const N: usize> Retain for staticvec::StaticVec<T, N> {
-  type Input = fn(&T) -> bool;
+  type Input = T;
   type Output = ();
 
   #[inline]
-  fn retain(&mut self, input: Self::Input) {
-    self.retain(input);
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&T) -> bool,
+  {
+    self.retain(|elem| input(elem));
   }
 }
 
@@ -109,12 +505,15 @@ where
   A: tinyvec::Array,
   A::Item: Default,
 {
-  type Input = fn(&A::Item) -> bool;
+  type Input = A::Item;
   type Output = ();
 
   #[inline]
-  fn retain(&mut self, input: Self::Input) {
-    self.retain(input);
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&A::Item) -> bool,
+  {
+    self.retain(|elem| input(elem));
   }
 }
 
@@ -129,11 +528,14 @@ where
   A: tinyvec::Array,
   A::Item: Default,
 {
-  type Input = fn(&A::Item) -> bool;
+  type Input = A::Item;
   type Output = ();
 
   #[inline]
-  fn retain(&mut self, input: Self::Input) {
-    self.retain(input);
+  fn retain<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&A::Item) -> bool,
+  {
+    self.retain(|elem| input(elem));
   }
 }
@@ -0,0 +1,212 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::BTreeMap};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// See [`retain_map`](RetainMap::retain_map) for more information.
+///
+/// Unlike [`Retain`](crate::Retain) and [`RetainMut`](crate::RetainMut), whose map predicates
+/// only receive values, the predicate also receives keys.
+pub trait RetainMap {
+  /// Key
+  type Key;
+  /// Output
+  type Output;
+  /// Value
+  type Value;
+
+  /// Retains only the entries specified by the `F` predicate, which can also modify values.
+  fn retain_map<F>(&mut self, input: F) -> Self::Output
+  where
+    F: FnMut(&Self::Key, &mut Self::Value) -> bool;
+}
+
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// let mut structure = cl_traits::doc_tests::b_tree_map();
+/// cl_traits::RetainMap::retain_map(&mut &mut structure, |k, _| *k != 0);
+/// assert_eq!(structure.len(), 2);
+/// # }
+/// ```
+impl<T> RetainMap for &'_ mut T
+where
+  T: RetainMap + ?Sized,
+{
+  type Key = T::Key;
+  type Output = T::Output;
+  type Value = T::Value;
+
+  #[inline]
+  fn retain_map<F>(&mut self, input: F) -> Self::Output
+  where
+    F: FnMut(&Self::Key, &mut Self::Value) -> bool,
+  {
+    (**self).retain_map(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::b_tree_map());
+/// cl_traits::RetainMap::retain_map(&mut structure, |k, _| *k != 0);
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> RetainMap for Box<T>
+where
+  T: RetainMap + ?Sized,
+{
+  type Key = T::Key;
+  type Output = T::Output;
+  type Value = T::Value;
+
+  #[inline]
+  fn retain_map<F>(&mut self, input: F) -> Self::Output
+  where
+    F: FnMut(&Self::Key, &mut Self::Value) -> bool,
+  {
+    (**self).retain_map(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::b_tree_map();
+/// let threshold = 2;
+/// cl_traits::RetainMap::retain_map(&mut structure, |k, v| {
+///   *v *= 10;
+///   *k >= threshold
+/// });
+/// assert_eq!(structure.get(&2), Some(&30));
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> RetainMap for BTreeMap<K, V>
+where
+  K: Ord,
+{
+  type Key = K;
+  type Output = ();
+  type Value = V;
+
+  #[inline]
+  fn retain_map<F>(&mut self, input: F)
+  where
+    F: FnMut(&K, &mut V) -> bool,
+  {
+    self.retain(input);
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_map();
+/// let threshold = 2;
+/// cl_traits::RetainMap::retain_map(&mut structure, |k, v| {
+///   *v *= 10;
+///   *k >= threshold
+/// });
+/// assert_eq!(structure.get(&2), Some(&30));
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> RetainMap for HashMap<K, V>
+where
+  K: Eq + core::hash::Hash,
+{
+  type Key = K;
+  type Output = ();
+  type Value = V;
+
+  #[inline]
+  fn retain_map<F>(&mut self, input: F)
+  where
+    F: FnMut(&K, &mut V) -> bool,
+  {
+    self.retain(input);
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// let threshold = 2;
+/// cl_traits::RetainMap::retain_map(&mut structure, |k, v| {
+///   *v *= 10;
+///   *k >= threshold
+/// });
+/// assert_eq!(structure.get(&2), Some(&30));
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> RetainMap for hashbrown::HashMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Key = K;
+  type Output = ();
+  type Value = V;
+
+  #[inline]
+  fn retain_map<F>(&mut self, input: F)
+  where
+    F: FnMut(&K, &mut V) -> bool,
+  {
+    self.retain(input);
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_index_map();
+/// let threshold = 2;
+/// cl_traits::RetainMap::retain_map(&mut structure, |k, v| {
+///   *v *= 10;
+///   *k >= threshold
+/// });
+/// assert_eq!(structure.get(&2), Some(&30));
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> RetainMap for heapless::IndexMap<K, V, S, N>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Key = K;
+  type Output = ();
+  type Value = V;
+
+  #[inline]
+  fn retain_map<F>(&mut self, input: F)
+  where
+    F: FnMut(&K, &mut V) -> bool,
+  {
+    self.retain(input);
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// let threshold = 2;
+/// cl_traits::RetainMap::retain_map(&mut structure, |k, v| {
+///   *v *= 10;
+///   *k >= threshold
+/// });
+/// assert_eq!(structure.get(&2), Some(&30));
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> RetainMap for indexmap::IndexMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Key = K;
+  type Output = ();
+  type Value = V;
+
+  #[inline]
+  fn retain_map<F>(&mut self, input: F)
+  where
+    F: FnMut(&K, &mut V) -> bool,
+  {
+    self.retain(input);
+  }
+}
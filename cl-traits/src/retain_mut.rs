@@ -0,0 +1,439 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, VecDeque},
+  vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[allow(
+  // For convenience because of selected features
+  unused
+)]
+// Manages vectors that don't provide a mutable retain: Kept elements are moved to the front,
+// preserving their relative order, and everything else is truncated.
+macro_rules! manage_vec {
+  ($v:expr, $input:expr) => {{
+    let slice = $v.as_mut_slice();
+    let mut kept: usize = 0;
+    for idx in 0..slice.len() {
+      if let Some(elem) = slice.get_mut(idx) {
+        if $input(elem) {
+          slice.swap(kept, idx);
+          kept = kept.wrapping_add(1);
+        }
+      }
+    }
+    $v.truncate(kept);
+  }};
+}
+
+/// See [`retain_mut`](RetainMut::retain_mut) for more information.
+///
+/// Maps provide their values. Sets don't implement this trait because their elements can't be
+/// mutated in place without invalidating their ordering or hashes.
+pub trait RetainMut {
+  /// Predicate input
+  type Input;
+  /// Output
+  type Output;
+
+  /// Retains only the elements specified by the `F` predicate, which can also modify them.
+  fn retain_mut<F>(&mut self, input: F) -> Self::Output
+  where
+    F: FnMut(&mut Self::Input) -> bool;
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::RetainMut::retain_mut(&mut &mut structure, |n| {
+///   *n *= 2;
+///   *n != 4
+/// });
+/// assert_eq!(&structure[..], &[2, 6]);
+/// ```
+impl<T> RetainMut for &'_ mut T
+where
+  T: RetainMut + ?Sized,
+{
+  type Input = T::Input;
+  type Output = T::Output;
+
+  #[inline]
+  fn retain_mut<F>(&mut self, input: F) -> Self::Output
+  where
+    F: FnMut(&mut T::Input) -> bool,
+  {
+    (**self).retain_mut(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::RetainMut::retain_mut(&mut structure, |n| {
+///   *n *= 2;
+///   *n != 4
+/// });
+/// assert_eq!(&structure[..], &[2, 6]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> RetainMut for Box<T>
+where
+  T: RetainMut + ?Sized,
+{
+  type Input = T::Input;
+  type Output = T::Output;
+
+  #[inline]
+  fn retain_mut<F>(&mut self, input: F) -> Self::Output
+  where
+    F: FnMut(&mut T::Input) -> bool,
+  {
+    (**self).retain_mut(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::b_tree_map();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |v| {
+///   *v *= 10;
+///   *v != 10
+/// });
+/// assert_eq!(structure.get(&1), Some(&20));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> RetainMut for BTreeMap<K, V>
+where
+  K: Ord,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut V) -> bool,
+  {
+    self.retain(|_, v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_map();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |v| {
+///   *v *= 10;
+///   *v != 10
+/// });
+/// assert_eq!(structure.get(&1), Some(&20));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> RetainMut for HashMap<K, V>
+where
+  K: Eq + core::hash::Hash,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut V) -> bool,
+  {
+    self.retain(|_, v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut opt = Some(1);
+/// cl_traits::RetainMut::retain_mut(&mut opt, |n| {
+///   *n += 1;
+///   true
+/// });
+/// assert_eq!(opt, Some(2));
+/// ```
+impl<T> RetainMut for Option<T> {
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut T) -> bool,
+  {
+    if let Some(elem) = self.as_mut() {
+      if !input(elem) {
+        *self = None;
+      }
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |n| {
+///   *n *= 2;
+///   *n != 4
+/// });
+/// assert_eq!(&structure[..], &[2, 6]);
+/// ```
+impl<T, const N: usize> RetainMut for crate::ArrayStorage<T, N> {
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, input: F)
+  where
+    F: FnMut(&mut T) -> bool,
+  {
+    self.retain_mut(input);
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |n| {
+///   *n *= 2;
+///   *n != 4
+/// });
+/// assert_eq!(&structure[..], &[2, 6]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> RetainMut for Vec<T> {
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut T) -> bool,
+  {
+    self.retain_mut(|elem| input(elem));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |n| {
+///   *n *= 2;
+///   *n != 4
+/// });
+/// assert_eq!(structure, &[2, 6]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> RetainMut for VecDeque<T> {
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut T) -> bool,
+  {
+    self.retain_mut(|elem| input(elem));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |n| {
+///   *n *= 2;
+///   *n != 4
+/// });
+/// assert_eq!(&structure[..], &[2, 6]);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> RetainMut for arrayvec::ArrayVec<T, N> {
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut T) -> bool,
+  {
+    self.retain(|elem| input(elem));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |v| {
+///   *v *= 10;
+///   *v != 10
+/// });
+/// assert_eq!(structure.get(&1), Some(&20));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> RetainMut for hashbrown::HashMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut V) -> bool,
+  {
+    self.retain(|_, v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_index_map();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |v| {
+///   *v *= 10;
+///   *v != 10
+/// });
+/// assert_eq!(structure.get(&1), Some(&20));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> RetainMut for heapless::IndexMap<K, V, S, N>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut V) -> bool,
+  {
+    self.retain(|_, v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |v| {
+///   *v *= 10;
+///   *v != 10
+/// });
+/// assert_eq!(structure.get(&1), Some(&20));
+/// assert_eq!(structure.len(), 2);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> RetainMut for indexmap::IndexMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = V;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut V) -> bool,
+  {
+    self.retain(|_, v| input(v));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |n| {
+///   *n *= 2;
+///   *n != 4
+/// });
+/// assert_eq!(&structure[..], &[2, 6]);
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> RetainMut for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Input = A::Item;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut A::Item) -> bool,
+  {
+    self.retain(|elem| input(elem));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |n| {
+///   *n *= 2;
+///   *n != 4
+/// });
+/// assert_eq!(&structure[..], &[2, 6]);
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> RetainMut for staticvec::StaticVec<T, N> {
+  type Input = T;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut T) -> bool,
+  {
+    let _ = self.drain_filter(|elem| !input(elem));
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |n| {
+///   *n *= 2;
+///   *n != 4
+/// });
+/// assert_eq!(&structure[..], &[2, 6]);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> RetainMut for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Input = A::Item;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut A::Item) -> bool,
+  {
+    manage_vec!(self, input);
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// cl_traits::RetainMut::retain_mut(&mut structure, |n| {
+///   *n *= 2;
+///   *n != 4
+/// });
+/// assert_eq!(&structure[..], &[2, 6]);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> RetainMut for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Input = A::Item;
+  type Output = ();
+
+  #[inline]
+  fn retain_mut<F>(&mut self, mut input: F)
+  where
+    F: FnMut(&mut A::Item) -> bool,
+  {
+    manage_vec!(self, input);
+  }
+}
@@ -0,0 +1,104 @@
+//! Helpers for `#[serde(with = "cl_traits::serde")]` that (de)serialize storages as sequences.
+//!
+//! ```rust
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Foo {
+//!   #[serde(with = "cl_traits::serde")]
+//!   bar: cl_traits::ArrayStorage<i32, 2>,
+//! }
+//!
+//! let foo: Foo = serde_json::from_str(r#"{"bar":[1,2]}"#).unwrap();
+//! assert_eq!(serde_json::to_string(&foo).unwrap(), r#"{"bar":[1,2]}"#);
+//! assert!(serde_json::from_str::<Foo>(r#"{"bar":[1,2,3]}"#).is_err());
+//! ```
+
+use crate::{Iter, Length, Push, WithCapacity};
+use ::serde::{
+  de::{Error, SeqAccess, Visitor},
+  ser::SerializeSeq,
+  Deserialize, Deserializer, Serialize, Serializer,
+};
+use core::{
+  fmt::{self, Display},
+  marker::PhantomData,
+  mem,
+};
+
+// Prevents untrusted length hints from allocating too much memory upfront
+const MAX_PREALLOCATION_BYTES: usize = 1024 * 1024;
+
+/// Deserializes a sequence into any storage that implements [`WithCapacity`] and [`Push`].
+///
+/// The sequence length hint, if any, is used as the initial capacity. Elements that can't be
+/// pushed, e.g., because a fixed-capacity storage is full, result in an error.
+///
+/// ```rust
+/// let mut de = serde_json::Deserializer::from_str("[1,2,3]");
+/// let rslt: Result<cl_traits::ArrayStorage<i32, 2>, _> = cl_traits::serde::deserialize(&mut de);
+/// assert_eq!(
+///   rslt.unwrap_err().to_string(),
+///   "element couldn't be inserted: the capacity of 2 would be exceeded at line 1 column 7"
+/// );
+/// ```
+#[inline]
+pub fn deserialize<'de, D, S>(deserializer: D) -> Result<S, D::Error>
+where
+  D: Deserializer<'de>,
+  S: Push + WithCapacity<Input = usize>,
+  <S as Push>::Error: Display,
+  <S as Push>::Input: Deserialize<'de>,
+{
+  deserializer.deserialize_seq(StorageVisitor(PhantomData))
+}
+
+/// Serializes any storage that implements [`Iter`] and [`Length`] as a sequence.
+///
+/// ```rust
+/// let mut buffer = Vec::new();
+/// let structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::serde::serialize(&structure, &mut serde_json::Serializer::new(&mut buffer)).unwrap();
+/// assert_eq!(buffer, b"[1,2,3]");
+/// ```
+#[inline]
+pub fn serialize<'storage, S, SE>(storage: &'storage S, serializer: SE) -> Result<SE::Ok, SE::Error>
+where
+  S: Iter + Length + ?Sized,
+  <S::Output<'storage> as Iterator>::Item: Serialize,
+  SE: Serializer,
+{
+  let mut seq = serializer.serialize_seq(Some(storage.length()))?;
+  for elem in storage.iter() {
+    seq.serialize_element(&elem)?;
+  }
+  seq.end()
+}
+
+struct StorageVisitor<S>(PhantomData<S>);
+
+impl<'de, S> Visitor<'de> for StorageVisitor<S>
+where
+  S: Push + WithCapacity<Input = usize>,
+  <S as Push>::Error: Display,
+  <S as Push>::Input: Deserialize<'de>,
+{
+  type Value = S;
+
+  #[inline]
+  fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    formatter.write_str("a sequence")
+  }
+
+  #[inline]
+  fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+  where
+    A: SeqAccess<'de>,
+  {
+    let elem_size = mem::size_of::<<S as Push>::Input>().max(1);
+    let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATION_BYTES / elem_size);
+    let mut storage = S::with_capacity(capacity);
+    while let Some(elem) = seq.next_element()? {
+      storage.push(elem).map_err(A::Error::custom)?;
+    }
+    Ok(storage)
+  }
+}
@@ -0,0 +1,399 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::VecDeque, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// See [`shrink_to_fit`](ShrinkToFit::shrink_to_fit) for more information.
+pub trait ShrinkToFit {
+  /// Input
+  type Input;
+  /// Output
+  type Output;
+
+  /// Shrinks the capacity as much as possible, never going below the current length or the
+  /// optional minimum capacity provided by `Input`.
+  fn shrink_to_fit(&mut self, input: Self::Input) -> Self::Output;
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut &mut structure, None);
+/// assert_eq!(structure.len(), 3);
+/// ```
+impl<T> ShrinkToFit for &'_ mut T
+where
+  T: ShrinkToFit + ?Sized,
+{
+  type Input = T::Input;
+  type Output = T::Output;
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) -> Self::Output {
+    (**self).shrink_to_fit(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert_eq!(structure.capacity(), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> ShrinkToFit for Box<T>
+where
+  T: ShrinkToFit + ?Sized,
+{
+  type Input = T::Input;
+  type Output = T::Output;
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) -> Self::Output {
+    (**self).shrink_to_fit(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_map();
+/// structure.reserve(100);
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, Some(10));
+/// assert!(structure.capacity() < 100);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> ShrinkToFit for HashMap<K, V>
+where
+  K: Eq + core::hash::Hash,
+{
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    match input {
+      None => self.shrink_to_fit(),
+      Some(min_capacity) => self.shrink_to(min_capacity),
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hash_set();
+/// structure.reserve(100);
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert!(structure.capacity() < 100);
+/// ```
+#[cfg(feature = "std")]
+impl<V> ShrinkToFit for HashSet<V>
+where
+  V: Eq + core::hash::Hash,
+{
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    match input {
+      None => self.shrink_to_fit(),
+      Some(min_capacity) => self.shrink_to(min_capacity),
+    }
+  }
+}
+
+/// ```rust
+/// let mut opt = Some(1);
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut opt, None);
+/// assert_eq!(opt, Some(1));
+/// ```
+impl<T> ShrinkToFit for Option<T> {
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, _: Self::Input) {}
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert_eq!(structure.len(), 3);
+/// ```
+impl<T, const N: usize> ShrinkToFit for [T; N] {
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, _: Self::Input) {}
+}
+
+/// ```rust
+/// let mut structure = String::with_capacity(10);
+/// structure.push_str("abc");
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, Some(5));
+/// assert_eq!(structure.capacity(), 5);
+/// ```
+#[cfg(feature = "alloc")]
+impl ShrinkToFit for String {
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    match input {
+      None => self.shrink_to_fit(),
+      Some(min_capacity) => self.shrink_to(min_capacity),
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert_eq!(structure.capacity(), 5);
+/// ```
+impl<T, const N: usize> ShrinkToFit for crate::ArrayStorage<T, N> {
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, _: Self::Input) {}
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert_eq!(structure.capacity(), 3);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> ShrinkToFit for Vec<T> {
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    match input {
+      None => self.shrink_to_fit(),
+      Some(min_capacity) => self.shrink_to(min_capacity),
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// structure.reserve(100);
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, Some(4));
+/// assert!(structure.capacity() < 100);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> ShrinkToFit for VecDeque<T> {
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    match input {
+      None => self.shrink_to_fit(),
+      Some(min_capacity) => self.shrink_to(min_capacity),
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert_eq!(structure.capacity(), 5);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> ShrinkToFit for arrayvec::ArrayVec<T, N> {
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, _: Self::Input) {}
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_map();
+/// structure.reserve(100);
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert!(structure.capacity() < 100);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> ShrinkToFit for hashbrown::HashMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    match input {
+      None => self.shrink_to_fit(),
+      Some(min_capacity) => self.shrink_to(min_capacity),
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::hashbrown_hash_set();
+/// structure.reserve(100);
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert!(structure.capacity() < 100);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> ShrinkToFit for hashbrown::HashSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    match input {
+      None => self.shrink_to_fit(),
+      Some(min_capacity) => self.shrink_to(min_capacity),
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// structure.reserve(100);
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert!(structure.capacity() < 100);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> ShrinkToFit for indexmap::IndexMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    match input {
+      None => self.shrink_to_fit(),
+      Some(min_capacity) => self.shrink_to(min_capacity),
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_set();
+/// structure.reserve(100);
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert!(structure.capacity() < 100);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> ShrinkToFit for indexmap::IndexSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    match input {
+      None => self.shrink_to_fit(),
+      Some(min_capacity) => self.shrink_to(min_capacity),
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// structure.extend([4, 5, 6].iter().copied());
+/// structure.truncate(3);
+/// assert!(structure.spilled());
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert!(!structure.spilled());
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> ShrinkToFit for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    match input {
+      None => self.shrink_to_fit(),
+      Some(min_capacity) => {
+        let new_capacity = self.len().max(min_capacity);
+        if new_capacity < self.capacity() {
+          self.grow(new_capacity);
+        }
+      }
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert_eq!(structure.capacity(), 5);
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> ShrinkToFit for staticvec::StaticVec<T, N> {
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, _: Self::Input) {}
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, None);
+/// assert_eq!(structure.capacity(), 5);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> ShrinkToFit for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, _: Self::Input) {}
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// structure.extend([4, 5, 6].iter().copied());
+/// structure.truncate(3);
+/// assert!(structure.is_heap());
+/// cl_traits::ShrinkToFit::shrink_to_fit(&mut structure, Some(4));
+/// assert!(structure.is_inline());
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> ShrinkToFit for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Input = Option<usize>;
+  type Output = ();
+
+  #[inline]
+  fn shrink_to_fit(&mut self, input: Self::Input) {
+    let new_capacity = match input {
+      None => self.len(),
+      Some(min_capacity) => self.len().max(min_capacity),
+    };
+    match self {
+      tinyvec::TinyVec::Heap(vec) if new_capacity > A::CAPACITY => vec.shrink_to(new_capacity),
+      _ => self.shrink_to_fit(),
+    }
+  }
+}
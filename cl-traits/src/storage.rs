@@ -1,5 +1,14 @@
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+  rc::Rc,
+  vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// Storage is anything that can hold a collection of items
 pub trait Storage {
@@ -7,6 +16,64 @@ pub trait Storage {
   type Item;
 }
 
+impl<T> Storage for &'_ T
+where
+  T: Storage + ?Sized,
+{
+  type Item = T::Item;
+}
+
+impl<T> Storage for &'_ mut T
+where
+  T: Storage + ?Sized,
+{
+  type Item = T::Item;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Storage for Box<T>
+where
+  T: Storage + ?Sized,
+{
+  type Item = T::Item;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Storage for Rc<T>
+where
+  T: Storage + ?Sized,
+{
+  type Item = T::Item;
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> Storage for Arc<T>
+where
+  T: Storage + ?Sized,
+{
+  type Item = T::Item;
+}
+
+#[cfg(feature = "alloc")]
+impl<K, V> Storage for BTreeMap<K, V> {
+  type Item = (K, V);
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Storage for BTreeSet<V> {
+  type Item = V;
+}
+
+#[cfg(feature = "std")]
+impl<K, V> Storage for HashMap<K, V> {
+  type Item = (K, V);
+}
+
+#[cfg(feature = "std")]
+impl<V> Storage for HashSet<V> {
+  type Item = V;
+}
+
 impl<T> Storage for Option<T> {
   type Item = T;
 }
@@ -15,11 +82,11 @@ impl<T, const N: usize> Storage for [T; N] {
   type Item = T;
 }
 
-impl<T> Storage for &'_ [T] {
+impl<T> Storage for [T] {
   type Item = T;
 }
 
-impl<T> Storage for &'_ mut [T] {
+impl<T, const N: usize> Storage for crate::ArrayStorage<T, N> {
   type Item = T;
 }
 
@@ -28,11 +95,61 @@ impl<T> Storage for Vec<T> {
   type Item = T;
 }
 
+#[cfg(feature = "alloc")]
+impl<T> Storage for LinkedList<T> {
+  type Item = T;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Storage for VecDeque<T> {
+  type Item = T;
+}
+
 #[cfg(feature = "with-arrayvec")]
 impl<T, const N: usize> Storage for arrayvec::ArrayVec<T, N> {
   type Item = T;
 }
 
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> Storage for hashbrown::HashMap<K, V, S> {
+  type Item = (K, V);
+}
+
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> Storage for hashbrown::HashSet<V, S> {
+  type Item = V;
+}
+
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Storage for heapless::Deque<T, N> {
+  type Item = T;
+}
+
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> Storage for heapless::IndexMap<K, V, S, N> {
+  type Item = (K, V);
+}
+
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> Storage for heapless::String<N> {
+  type Item = char;
+}
+
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Storage for heapless::Vec<T, N> {
+  type Item = T;
+}
+
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Storage for indexmap::IndexMap<K, V, S> {
+  type Item = (K, V);
+}
+
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Storage for indexmap::IndexSet<V, S> {
+  type Item = V;
+}
+
 #[cfg(feature = "with-smallvec")]
 impl<A> Storage for smallvec::SmallVec<A>
 where
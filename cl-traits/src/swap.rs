@@ -1,10 +1,12 @@
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use core::fmt;
 
 macro_rules! vec_swap {
   ($v:expr, $a:expr, $b:expr) => {{
-    if $a >= $v.len() || $b >= $v.len() {
-      return Err(());
+    let len = $v.len();
+    if $a >= len || $b >= len {
+      return Err(SwapOutOfBounds { a: $a, b: $b, len });
     }
     let slice: &mut [_] = $v.as_mut();
     slice.swap($a, $b);
@@ -12,7 +14,32 @@ macro_rules! vec_swap {
   }};
 }
 
+/// Error of [`Swap`] when at least one of the referenced indices is greater than or equal to the
+/// length.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SwapOutOfBounds {
+  /// First provided index
+  pub a: usize,
+  /// Second provided index
+  pub b: usize,
+  /// Length of the collection
+  pub len: usize,
+}
+
+impl fmt::Display for SwapOutOfBounds {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "indices {} and {} can't be swapped for a length of {}", self.a, self.b, self.len)
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SwapOutOfBounds {}
+
 /// See [`Swap`](Swap::swap) for more information.
+///
+/// `heapless::IndexMap` doesn't implement this trait because `heapless` 0.8 provides no way to
+/// reorder its entries.
 pub trait Swap {
   /// Input
   type Input;
@@ -23,6 +50,43 @@ pub trait Swap {
   fn swap(&mut self, input: Self::Input) -> Self::Output;
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array();
+/// cl_traits::Swap::swap(&mut &mut structure, [0, 2]);
+/// assert_eq!(structure, [3, 2, 1]);
+/// ```
+impl<T> Swap for &'_ mut T
+where
+  T: Swap + ?Sized,
+{
+  type Input = T::Input;
+  type Output = T::Output;
+
+  #[inline]
+  fn swap(&mut self, input: Self::Input) -> Self::Output {
+    (**self).swap(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::Swap::swap(&mut structure, [0, 2]);
+/// assert_eq!(&structure[..], &[3, 2, 1]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Swap for Box<T>
+where
+  T: Swap + ?Sized,
+{
+  type Input = T::Input;
+  type Output = T::Output;
+
+  #[inline]
+  fn swap(&mut self, input: Self::Input) -> Self::Output {
+    (**self).swap(input)
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::array();
 /// cl_traits::Swap::swap(&mut structure, [0, 2]);
@@ -31,7 +95,7 @@ pub trait Swap {
 /// ```
 impl<T, const N: usize> Swap for [T; N] {
   type Input = [usize; 2];
-  type Output = Result<(), ()>;
+  type Output = Result<(), SwapOutOfBounds>;
 
   #[inline]
   fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
@@ -40,14 +104,29 @@ impl<T, const N: usize> Swap for [T; N] {
 }
 
 /// ```rust
-/// let mut structure = &mut [1, 2, 3][..];
+/// let mut array = [1, 2, 3];
+/// cl_traits::Swap::swap(&mut array[..], [0, 2]);
+/// assert_eq!(array, [3, 2, 1]);
+/// ```
+impl<T> Swap for [T] {
+  type Input = [usize; 2];
+  type Output = Result<(), SwapOutOfBounds>;
+
+  #[inline]
+  fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
+    vec_swap!(self, a, b)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
 /// cl_traits::Swap::swap(&mut structure, [0, 2]);
 /// assert_eq!(structure.get(0), Some(&3));
 /// assert_eq!(structure.get(2), Some(&1));
 /// ```
-impl<T> Swap for &'_ mut [T] {
+impl<T, const N: usize> Swap for crate::ArrayStorage<T, N> {
   type Input = [usize; 2];
-  type Output = Result<(), ()>;
+  type Output = Result<(), SwapOutOfBounds>;
 
   #[inline]
   fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
@@ -60,11 +139,15 @@ impl<T> Swap for &'_ mut [T] {
 /// cl_traits::Swap::swap(&mut structure, [0, 2]);
 /// assert_eq!(structure.get(0), Some(&3));
 /// assert_eq!(structure.get(2), Some(&1));
+/// assert_eq!(
+///   cl_traits::Swap::swap(&mut structure, [0, 3]),
+///   Err(cl_traits::SwapOutOfBounds { a: 0, b: 3, len: 3 })
+/// );
 /// ```
 #[cfg(feature = "alloc")]
 impl<T> Swap for Vec<T> {
   type Input = [usize; 2];
-  type Output = Result<(), ()>;
+  type Output = Result<(), SwapOutOfBounds>;
 
   #[inline]
   fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
@@ -72,6 +155,28 @@ impl<T> Swap for Vec<T> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::Swap::swap(&mut structure, [0, 2]);
+/// assert_eq!(structure.get(0), Some(&3));
+/// assert_eq!(structure.get(2), Some(&1));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Swap for VecDeque<T> {
+  type Input = [usize; 2];
+  type Output = Result<(), SwapOutOfBounds>;
+
+  #[inline]
+  fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
+    let len = self.len();
+    if a >= len || b >= len {
+      return Err(SwapOutOfBounds { a, b, len });
+    }
+    self.swap(a, b);
+    Ok(())
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::array_vec();
 /// cl_traits::Swap::swap(&mut structure, [0, 2]);
@@ -81,7 +186,92 @@ impl<T> Swap for Vec<T> {
 #[cfg(feature = "with-arrayvec")]
 impl<T, const N: usize> Swap for arrayvec::ArrayVec<T, N> {
   type Input = [usize; 2];
-  type Output = Result<(), ()>;
+  type Output = Result<(), SwapOutOfBounds>;
+
+  #[inline]
+  fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
+    vec_swap!(self, a, b)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_deque();
+/// cl_traits::Swap::swap(&mut structure, [0, 2]);
+/// assert_eq!(structure.iter().copied().collect::<Vec<_>>(), [3, 2, 1]);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Swap for heapless::Deque<T, N> {
+  type Input = [usize; 2];
+  type Output = Result<(), SwapOutOfBounds>;
+
+  #[inline]
+  fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
+    let len = self.len();
+    if a >= len || b >= len {
+      return Err(SwapOutOfBounds { a, b, len });
+    }
+    let (lower, upper) = if a <= b { (a, b) } else { (b, a) };
+    let (front, back) = self.as_mut_slices();
+    let front_len = front.len();
+    if upper < front_len {
+      front.swap(lower, upper);
+    } else if lower >= front_len {
+      back.swap(lower.wrapping_sub(front_len), upper.wrapping_sub(front_len));
+    } else if let (Some(lhs), Some(rhs)) =
+      (front.get_mut(lower), back.get_mut(upper.wrapping_sub(front_len)))
+    {
+      core::mem::swap(lhs, rhs);
+    }
+    Ok(())
+  }
+}
+
+/// Indices refer to characters instead of bytes, which makes every swap valid UTF-8.
+///
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_string();
+/// structure.push('é').unwrap();
+/// assert_eq!(cl_traits::Swap::swap(&mut structure, [0, 3]), Ok(()));
+/// assert_eq!(structure.as_str(), "ébca");
+/// assert!(cl_traits::Swap::swap(&mut structure, [0, 4]).is_err());
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> Swap for heapless::String<N> {
+  type Input = [usize; 2];
+  type Output = Result<(), SwapOutOfBounds>;
+
+  #[inline]
+  fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
+    let len = self.chars().count();
+    let (Some(char_a), Some(char_b)) = (self.chars().nth(a), self.chars().nth(b)) else {
+      return Err(SwapOutOfBounds { a, b, len });
+    };
+    let prev = core::mem::take(self);
+    for (idx, elem) in prev.chars().enumerate() {
+      let elem = if idx == a {
+        char_b
+      } else if idx == b {
+        char_a
+      } else {
+        elem
+      };
+      // Can't fail because the number of bytes stays the same
+      let _ = self.push(elem);
+    }
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_vec();
+/// cl_traits::Swap::swap(&mut structure, [0, 2]);
+/// assert_eq!(structure.get(0), Some(&3));
+/// assert_eq!(structure.get(2), Some(&1));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Swap for heapless::Vec<T, N> {
+  type Input = [usize; 2];
+  type Output = Result<(), SwapOutOfBounds>;
 
   #[inline]
   fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
@@ -89,6 +279,48 @@ impl<T, const N: usize> Swap for arrayvec::ArrayVec<T, N> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// cl_traits::Swap::swap(&mut structure, [0, 2]);
+/// assert_eq!(structure.get_index(0), Some((&2, &3)));
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Swap for indexmap::IndexMap<K, V, S> {
+  type Input = [usize; 2];
+  type Output = Result<(), SwapOutOfBounds>;
+
+  #[inline]
+  fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
+    let len = self.len();
+    if a >= len || b >= len {
+      return Err(SwapOutOfBounds { a, b, len });
+    }
+    self.swap_indices(a, b);
+    Ok(())
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_set();
+/// cl_traits::Swap::swap(&mut structure, [0, 2]);
+/// assert_eq!(structure.get_index(0), Some(&3));
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Swap for indexmap::IndexSet<V, S> {
+  type Input = [usize; 2];
+  type Output = Result<(), SwapOutOfBounds>;
+
+  #[inline]
+  fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
+    let len = self.len();
+    if a >= len || b >= len {
+      return Err(SwapOutOfBounds { a, b, len });
+    }
+    self.swap_indices(a, b);
+    Ok(())
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::small_vec();
 /// cl_traits::Swap::swap(&mut structure, [0, 2]);
@@ -101,7 +333,7 @@ where
   A: smallvec::Array,
 {
   type Input = [usize; 2];
-  type Output = Result<(), ()>;
+  type Output = Result<(), SwapOutOfBounds>;
 
   #[inline]
   fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
@@ -118,7 +350,7 @@ where
 #[cfg(feature = "with-staticvec")]
 impl<T, const N: usize> Swap for staticvec::StaticVec<T, N> {
   type Input = [usize; 2];
-  type Output = Result<(), ()>;
+  type Output = Result<(), SwapOutOfBounds>;
 
   #[inline]
   fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
@@ -139,7 +371,7 @@ where
   A::Item: Default,
 {
   type Input = [usize; 2];
-  type Output = Result<(), ()>;
+  type Output = Result<(), SwapOutOfBounds>;
 
   #[inline]
   fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
@@ -160,7 +392,7 @@ where
   A::Item: Default,
 {
   type Input = [usize; 2];
-  type Output = Result<(), ()>;
+  type Output = Result<(), SwapOutOfBounds>;
 
   #[inline]
   fn swap(&mut self, [a, b]: Self::Input) -> Self::Output {
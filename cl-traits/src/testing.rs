@@ -0,0 +1,153 @@
+//! Reusable checks that assert the laws connecting the traits of this crate.
+//!
+//! Every function panics with a descriptive message when the given storage violates a law, so
+//! they can be called from the tests of any downstream implementation.
+//!
+//! The laws assume that [`Length`] counts the same elements that [`Push`] appends, which is why
+//! string types, whose length is measured in bytes, are not suitable.
+//!
+//! Storages are compared element-wise through [`Iter`] instead of [`PartialEq`]. The element bound
+//! has to hold for every borrow of the storage, so only storages without borrowed data qualify.
+//!
+//! ```rust
+//! let mut structure = cl_traits::ArrayStorage::<i32, 2>::new();
+//! cl_traits::testing::check_laws(&mut structure, [1, 2, 3]);
+//! ```
+
+use crate::{Capacity, CapacityUpperBound, Clear, Iter, Length, Push, Truncate};
+use core::fmt::Debug;
+
+/// Asserts that `length <= capacity <= capacity_upper_bound`.
+///
+/// ```rust
+/// cl_traits::testing::check_capacity(&cl_traits::doc_tests::array_storage());
+/// ```
+#[inline]
+pub fn check_capacity<S>(storage: &S)
+where
+  S: Capacity + CapacityUpperBound + Length + ?Sized,
+{
+  let length = storage.length();
+  let capacity = storage.capacity();
+  let capacity_upper_bound = storage.capacity_upper_bound();
+  assert!(length <= capacity, "length (is {}) should be <= capacity (is {})", length, capacity);
+  assert!(
+    capacity <= capacity_upper_bound,
+    "capacity (is {}) should be <= capacity upper bound (is {})",
+    capacity,
+    capacity_upper_bound
+  );
+}
+
+/// Asserts that [`Clear`] leaves a length of zero.
+///
+/// ```rust
+/// cl_traits::testing::check_clear(&mut cl_traits::doc_tests::array_storage());
+/// ```
+#[inline]
+pub fn check_clear<S>(storage: &mut S)
+where
+  S: Clear + Length + ?Sized,
+{
+  storage.clear();
+  let length = storage.length();
+  assert!(length == 0, "length (is {}) should be 0 after clear", length);
+}
+
+/// Pushes `input` and asserts that a successful push increments the length by one while a failed
+/// push leaves `storage` unchanged.
+///
+/// Returns the result of the push.
+///
+/// ```rust
+/// let mut structure = cl_traits::ArrayStorage::<i32, 1>::new();
+/// assert!(cl_traits::testing::check_push(&mut structure, 1).is_ok());
+/// assert!(cl_traits::testing::check_push(&mut structure, 2).is_err());
+/// ```
+#[inline]
+pub fn check_push<S>(storage: &mut S, input: S::Input) -> Result<S::Ok, S::Error>
+where
+  S: Clone + Debug + Iter + Length + Push,
+  for<'any> <<S as Iter>::Output<'any> as Iterator>::Item: PartialEq,
+{
+  let before = storage.clone();
+  let length = storage.length();
+  let rslt = storage.push(input);
+  if rslt.is_ok() {
+    let expected = length.wrapping_add(1);
+    let actual = storage.length();
+    assert!(
+      actual == expected,
+      "length (is {}) should be {} after a successful push",
+      actual,
+      expected
+    );
+  } else {
+    assert!(
+      storage.iter().eq(before.iter()),
+      "storage (is {:?}) should be {:?} after a failed push",
+      storage,
+      before
+    );
+  }
+  rslt
+}
+
+/// Truncates `storage` and asserts that `min(length, len)` elements are left.
+///
+/// ```rust
+/// cl_traits::testing::check_truncate(&mut cl_traits::doc_tests::array_storage(), 1);
+/// ```
+#[inline]
+pub fn check_truncate<S>(storage: &mut S, len: usize)
+where
+  S: Length + Truncate<Input = usize, Output = ()> + ?Sized,
+{
+  let expected = storage.length().min(len);
+  storage.truncate(len);
+  let actual = storage.length();
+  assert!(
+    actual == expected,
+    "length (is {}) should be {} after truncate({})",
+    actual,
+    expected,
+    len
+  );
+}
+
+/// Runs every check of this module: pushes all `inputs`, truncates to half of the resulting
+/// length and then clears `storage`, verifying the capacity relation after each step.
+///
+/// Supply more `inputs` than the capacity of fixed-capacity storages to also cover failed pushes.
+///
+/// ```rust
+/// let mut structure = cl_traits::ArrayStorage::<i32, 5>::new();
+/// cl_traits::testing::check_laws(&mut structure, 0..10);
+/// ```
+#[inline]
+pub fn check_laws<I, S>(storage: &mut S, inputs: I)
+where
+  I: IntoIterator<Item = <S as Push>::Input>,
+  S: Capacity
+    + CapacityUpperBound
+    + Clear
+    + Clone
+    + Debug
+    + Iter
+    + Length
+    + Push
+    + Truncate<Input = usize, Output = ()>,
+  for<'any> <<S as Iter>::Output<'any> as Iterator>::Item: PartialEq,
+{
+  check_capacity(storage);
+  for input in inputs {
+    let _ = check_push(storage, input);
+    check_capacity(storage);
+  }
+  let length = storage.length();
+  check_truncate(storage, length.wrapping_add(1));
+  check_truncate(storage, length.wrapping_div(2));
+  check_capacity(storage);
+  check_clear(storage);
+  check_capacity(storage);
+}
@@ -1,5 +1,41 @@
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, collections::VecDeque, string::String, vec::Vec};
+use core::fmt;
+
+#[allow(
+  // For convenience because of selected features
+  unused
+)]
+// Truncates strings only at `char` boundaries
+macro_rules! manage_str {
+  ($s:expr, $len:expr) => {{
+    if $len >= $s.len() {
+      return Ok(());
+    }
+    if !$s.is_char_boundary($len) {
+      return Err(CharBoundaryError { index: $len });
+    }
+    $s.truncate($len);
+    Ok(())
+  }};
+}
+
+/// Error of string operations when the referenced byte index doesn't lie on a `char` boundary.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CharBoundaryError {
+  /// Provided byte index
+  pub index: usize,
+}
+
+impl fmt::Display for CharBoundaryError {
+  #[inline]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "byte index {} is not a char boundary", self.index)
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CharBoundaryError {}
 
 /// See [`Truncate`](Truncate::truncate) for more information.
 pub trait Truncate {
@@ -12,6 +48,43 @@ pub trait Truncate {
   fn truncate(&mut self, input: Self::Input) -> Self::Output;
 }
 
+/// ```rust
+/// let mut structure = Some(1);
+/// cl_traits::Truncate::truncate(&mut &mut structure, 0);
+/// assert_eq!(structure, None);
+/// ```
+impl<T> Truncate for &'_ mut T
+where
+  T: Truncate + ?Sized,
+{
+  type Input = T::Input;
+  type Output = T::Output;
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) -> Self::Output {
+    (**self).truncate(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::Truncate::truncate(&mut structure, 1);
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Truncate for Box<T>
+where
+  T: Truncate + ?Sized,
+{
+  type Input = T::Input;
+  type Output = T::Output;
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) -> Self::Output {
+    (**self).truncate(input)
+  }
+}
+
 /// ```rust
 /// let structure: Option<i32> = cl_traits::WithCapacity::with_capacity(Default::default());
 /// assert_eq!(structure, None);
@@ -28,6 +101,41 @@ impl<T> Truncate for Option<T> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// cl_traits::Truncate::truncate(&mut structure, 1);
+/// assert_eq!(structure.len(), 1);
+/// ```
+impl<T, const N: usize> Truncate for crate::ArrayStorage<T, N> {
+  type Input = usize;
+  type Output = ();
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) {
+    self.truncate(input);
+  }
+}
+
+/// ```rust
+/// let mut structure = String::from("aé");
+/// assert_eq!(
+///   cl_traits::Truncate::truncate(&mut structure, 2),
+///   Err(cl_traits::CharBoundaryError { index: 2 })
+/// );
+/// assert_eq!(cl_traits::Truncate::truncate(&mut structure, 1), Ok(()));
+/// assert_eq!(structure, "a");
+/// ```
+#[cfg(feature = "alloc")]
+impl Truncate for String {
+  type Input = usize;
+  type Output = Result<(), CharBoundaryError>;
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) -> Self::Output {
+    manage_str!(self, input)
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::vec();
 /// cl_traits::Truncate::truncate(&mut structure, 1);
@@ -44,6 +152,38 @@ impl<T> Truncate for Vec<T> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::Truncate::truncate(&mut structure, 1);
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Truncate for VecDeque<T> {
+  type Input = usize;
+  type Output = ();
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) {
+    self.truncate(input);
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_string();
+/// assert_eq!(cl_traits::Truncate::truncate(&mut structure, 1), Ok(()));
+/// assert_eq!(structure.as_str(), "a");
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<const CAP: usize> Truncate for arrayvec::ArrayString<CAP> {
+  type Input = usize;
+  type Output = Result<(), CharBoundaryError>;
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) -> Self::Output {
+    manage_str!(self, input)
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::array_vec();
 /// cl_traits::Truncate::truncate(&mut structure, 1);
@@ -60,6 +200,114 @@ impl<T, const N: usize> Truncate for arrayvec::ArrayVec<T, N> {
   }
 }
 
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_deque();
+/// cl_traits::Truncate::truncate(&mut structure, 1);
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Truncate for heapless::Deque<T, N> {
+  type Input = usize;
+  type Output = ();
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) {
+    for _ in input..self.len() {
+      let _ = self.pop_back();
+    }
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_index_map();
+/// cl_traits::Truncate::truncate(&mut structure, 1);
+/// assert_eq!(structure.len(), 1);
+/// assert_eq!(structure.get(&0), Some(&1));
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> Truncate for heapless::IndexMap<K, V, S, N>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher,
+{
+  type Input = usize;
+  type Output = ();
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) {
+    // Entries are visited in insertion order
+    let mut kept: usize = 0;
+    self.retain(|_, _| {
+      kept = kept.wrapping_add(1);
+      kept <= input
+    });
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_string();
+/// assert_eq!(cl_traits::Truncate::truncate(&mut structure, 1), Ok(()));
+/// assert_eq!(structure.as_str(), "a");
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> Truncate for heapless::String<N> {
+  type Input = usize;
+  type Output = Result<(), CharBoundaryError>;
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) -> Self::Output {
+    manage_str!(self, input)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::heapless_vec();
+/// cl_traits::Truncate::truncate(&mut structure, 1);
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> Truncate for heapless::Vec<T, N> {
+  type Input = usize;
+  type Output = ();
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) {
+    self.truncate(input);
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_map();
+/// cl_traits::Truncate::truncate(&mut structure, 1);
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> Truncate for indexmap::IndexMap<K, V, S> {
+  type Input = usize;
+  type Output = ();
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) {
+    self.truncate(input);
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::index_set();
+/// cl_traits::Truncate::truncate(&mut structure, 1);
+/// assert_eq!(structure.len(), 1);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> Truncate for indexmap::IndexSet<V, S> {
+  type Input = usize;
+  type Output = ();
+
+  #[inline]
+  fn truncate(&mut self, input: Self::Input) {
+    self.truncate(input);
+  }
+}
+
 /// ```rust
 /// let mut structure = cl_traits::doc_tests::small_vec();
 /// cl_traits::Truncate::truncate(&mut structure, 1);
@@ -0,0 +1,252 @@
+use crate::{reserve::reserve_fixed, ReserveError};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+
+#[allow(
+  // For convenience because of selected features
+  unused
+)]
+// Manages heap-allocated storages, telling the upper bound apart from allocator failures
+macro_rules! manage_heap {
+  ($v:expr, $additional:expr, $cb:expr) => {{
+    let capacity_upper_bound = crate::CapacityUpperBound::capacity_upper_bound($v);
+    let requested = $v.len().saturating_add($additional);
+    if requested > capacity_upper_bound {
+      return Err(ReserveError::CapacityUpperBoundExceeded { capacity_upper_bound, requested });
+    }
+    $cb($v).map_err(|_| ReserveError::AllocError)
+  }};
+}
+
+/// See [`try_reserve`](TryReserve::try_reserve) for more information.
+pub trait TryReserve {
+  /// Error
+  type Error;
+  /// Input
+  type Input;
+  /// Ok
+  type Ok;
+
+  /// Tries to reserve capacity for at least `Input` more elements, returning an error instead of
+  /// panicking or aborting.
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error>;
+}
+
+/// ```rust
+/// let mut structure = None::<i32>;
+/// assert!(cl_traits::TryReserve::try_reserve(&mut &mut structure, 2).is_err());
+/// ```
+impl<T> TryReserve for &'_ mut T
+where
+  T: TryReserve + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).try_reserve(input)
+  }
+}
+
+/// ```rust
+/// let mut structure = Box::new(cl_traits::doc_tests::vec());
+/// cl_traits::TryReserve::try_reserve(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> TryReserve for Box<T>
+where
+  T: TryReserve + ?Sized,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+  type Ok = T::Ok;
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    (**self).try_reserve(input)
+  }
+}
+
+/// ```rust
+/// let mut opt = None::<i32>;
+/// assert!(cl_traits::TryReserve::try_reserve(&mut opt, 1).is_ok());
+/// assert!(cl_traits::TryReserve::try_reserve(&mut opt, 2).is_err());
+/// ```
+impl<T> TryReserve for Option<T> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(crate::Length::length(self), input, 1)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_storage();
+/// assert!(cl_traits::TryReserve::try_reserve(&mut structure, 2).is_ok());
+/// assert!(cl_traits::TryReserve::try_reserve(&mut structure, 3).is_err());
+/// ```
+impl<T, const N: usize> TryReserve for crate::ArrayStorage<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, N)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec();
+/// cl_traits::TryReserve::try_reserve(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// assert!(matches!(
+///   cl_traits::TryReserve::try_reserve(&mut structure, usize::MAX),
+///   Err(cl_traits::ReserveError::CapacityUpperBoundExceeded { .. })
+/// ));
+/// let mut zst = Vec::<()>::new();
+/// assert_eq!(cl_traits::TryReserve::try_reserve(&mut zst, 2), Ok(()));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> TryReserve for Vec<T> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    manage_heap!(self, input, |this: &mut Self| this.try_reserve(input))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::vec_deque();
+/// cl_traits::TryReserve::try_reserve(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> TryReserve for VecDeque<T> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    manage_heap!(self, input, |this: &mut Self| this.try_reserve(input))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::small_vec();
+/// cl_traits::TryReserve::try_reserve(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> TryReserve for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    manage_heap!(self, input, |this: &mut Self| this.try_reserve(input))
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec();
+/// cl_traits::TryReserve::try_reserve(&mut structure, 10);
+/// assert!(structure.capacity() >= 13);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> TryReserve for tinyvec::TinyVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    manage_heap!(self, input, |this: &mut Self| match this {
+      tinyvec::TinyVec::Heap(vec) => vec.try_reserve(input),
+      tinyvec::TinyVec::Inline(array) => {
+        let requested = array.len().saturating_add(input);
+        if requested <= A::CAPACITY {
+          return Ok(());
+        }
+        let mut vec = Vec::new();
+        vec.try_reserve(requested)?;
+        vec.extend(array.drain(..));
+        *this = tinyvec::TinyVec::Heap(vec);
+        Ok(())
+      }
+    })
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::array_vec();
+/// assert!(cl_traits::TryReserve::try_reserve(&mut structure, 2).is_ok());
+/// assert!(cl_traits::TryReserve::try_reserve(&mut structure, 3).is_err());
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> TryReserve for arrayvec::ArrayVec<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, N)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::static_vec();
+/// assert!(cl_traits::TryReserve::try_reserve(&mut structure, 2).is_ok());
+/// assert!(cl_traits::TryReserve::try_reserve(&mut structure, 3).is_err());
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> TryReserve for staticvec::StaticVec<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, N)
+  }
+}
+
+/// ```rust
+/// let mut structure = cl_traits::doc_tests::tiny_vec_array_vec();
+/// assert!(cl_traits::TryReserve::try_reserve(&mut structure, 2).is_ok());
+/// assert!(cl_traits::TryReserve::try_reserve(&mut structure, 3).is_err());
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> TryReserve for tinyvec::ArrayVec<A>
+where
+  A: tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+  type Ok = ();
+
+  #[inline]
+  fn try_reserve(&mut self, input: Self::Input) -> Result<Self::Ok, Self::Error> {
+    reserve_fixed(self.len(), input, A::CAPACITY)
+  }
+}
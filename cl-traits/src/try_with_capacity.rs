@@ -0,0 +1,404 @@
+use crate::{reserve::reserve_fixed, CapacityUpperBound, ReserveError};
+#[cfg(feature = "alloc")]
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, BTreeSet, VecDeque},
+  vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+// Checks the upper bound of an empty instance and then tries to allocate through `$cb`, if any
+macro_rules! manage {
+  ($this:expr, $input:expr) => {{
+    let this = $this;
+    reserve_fixed(0, $input, CapacityUpperBound::capacity_upper_bound(&this))?;
+    Ok(this)
+  }};
+  ($this:expr, $input:expr, $cb:expr) => {{
+    let mut this = $this;
+    reserve_fixed(0, $input, CapacityUpperBound::capacity_upper_bound(&this))?;
+    $cb(&mut this).map_err(|_| ReserveError::AllocError)?;
+    Ok(this)
+  }};
+}
+
+/// See [`try_with_capacity`](TryWithCapacity::try_with_capacity) for more information.
+pub trait TryWithCapacity: Sized {
+  /// Error
+  type Error;
+  /// Input
+  type Input;
+
+  /// Tries to create a new instance that is able to hold at least `Input` elements, returning an
+  /// error instead of panicking, aborting or silently holding less.
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error>;
+}
+
+/// ```rust
+/// let structure: Box<Vec<i32>> = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert_eq!(structure.capacity(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> TryWithCapacity for Box<T>
+where
+  T: TryWithCapacity,
+{
+  type Error = T::Error;
+  type Input = T::Input;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    T::try_with_capacity(input).map(Box::new)
+  }
+}
+
+/// ```rust
+/// let structure: std::collections::BTreeMap<i32, i32>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> TryWithCapacity for BTreeMap<K, V>
+where
+  K: Ord,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(BTreeMap::new(), input)
+  }
+}
+
+/// ```rust
+/// let structure: std::collections::BTreeSet<i32>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<V> TryWithCapacity for BTreeSet<V>
+where
+  V: Ord,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(BTreeSet::new(), input)
+  }
+}
+
+/// ```rust
+/// let structure: std::collections::HashMap<i32, i32>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> TryWithCapacity for HashMap<K, V>
+where
+  K: Eq + core::hash::Hash,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(HashMap::new(), input, |this: &mut Self| this.try_reserve(input))
+  }
+}
+
+/// ```rust
+/// let structure: std::collections::HashSet<i32>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "std")]
+impl<V> TryWithCapacity for HashSet<V>
+where
+  V: Eq + core::hash::Hash,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(HashSet::new(), input, |this: &mut Self| this.try_reserve(input))
+  }
+}
+
+/// ```rust
+/// let structure: Result<Option<i32>, _> = cl_traits::TryWithCapacity::try_with_capacity(1);
+/// assert_eq!(structure, Ok(None));
+/// let structure: Result<Option<i32>, _> = cl_traits::TryWithCapacity::try_with_capacity(2);
+/// assert!(structure.is_err());
+/// ```
+impl<T> TryWithCapacity for Option<T> {
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(None, input)
+  }
+}
+
+/// ```rust
+/// let structure: Result<[i32; 3], _> = cl_traits::TryWithCapacity::try_with_capacity(3);
+/// assert_eq!(structure, Ok([0, 0, 0]));
+/// let structure: Result<[i32; 3], _> = cl_traits::TryWithCapacity::try_with_capacity(4);
+/// assert!(structure.is_err());
+/// ```
+impl<T, const N: usize> TryWithCapacity for [T; N]
+where
+  T: Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    reserve_fixed(0, input, N)?;
+    Ok(crate::create_array(|_| T::default()))
+  }
+}
+
+/// ```rust
+/// let structure: Result<cl_traits::ArrayStorage<i32, 5>, _>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(1000);
+/// assert!(structure.is_err());
+/// ```
+impl<T, const N: usize> TryWithCapacity for crate::ArrayStorage<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(crate::ArrayStorage::new(), input)
+  }
+}
+
+/// ```rust
+/// let structure: Vec<i32> = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert_eq!(structure.capacity(), 2);
+/// assert!(matches!(
+///   <Vec<i32> as cl_traits::TryWithCapacity>::try_with_capacity(usize::MAX),
+///   Err(cl_traits::ReserveError::CapacityUpperBoundExceeded { .. })
+/// ));
+/// let zst: Vec<()> = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert_eq!(zst.capacity(), usize::MAX);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> TryWithCapacity for Vec<T> {
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(Vec::new(), input, |this: &mut Self| this.try_reserve_exact(input))
+  }
+}
+
+/// ```rust
+/// let structure: std::collections::VecDeque<i32>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert!(structure.capacity() >= 2);
+/// let zst: Result<std::collections::VecDeque<()>, _>;
+/// zst = cl_traits::TryWithCapacity::try_with_capacity(2);
+/// assert!(zst.is_ok());
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> TryWithCapacity for VecDeque<T> {
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(VecDeque::new(), input, |this: &mut Self| this.try_reserve_exact(input))
+  }
+}
+
+/// ```rust
+/// let structure: Result<arrayvec::ArrayVec<i32, 5>, _>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(1000);
+/// assert_eq!(
+///   structure,
+///   Err(cl_traits::ReserveError::CapacityUpperBoundExceeded {
+///     capacity_upper_bound: 5,
+///     requested: 1000
+///   })
+/// );
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<T, const N: usize> TryWithCapacity for arrayvec::ArrayVec<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(arrayvec::ArrayVec::new(), input)
+  }
+}
+
+/// ```rust
+/// let structure: hashbrown::HashMap<i32, i32>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> TryWithCapacity for hashbrown::HashMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher + Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(hashbrown::HashMap::with_hasher(S::default()), input, |this: &mut Self| this
+      .try_reserve(input))
+  }
+}
+
+/// ```rust
+/// let structure: hashbrown::HashSet<i32>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> TryWithCapacity for hashbrown::HashSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher + Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(hashbrown::HashSet::with_hasher(S::default()), input, |this: &mut Self| this
+      .try_reserve(input))
+  }
+}
+
+/// ```rust
+/// let structure: indexmap::IndexMap<i32, i32, cl_traits::doc_tests::FnvBuildHasher>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> TryWithCapacity for indexmap::IndexMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher + Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(indexmap::IndexMap::with_hasher(S::default()), input, |this: &mut Self| this
+      .try_reserve(input))
+  }
+}
+
+/// ```rust
+/// let structure: indexmap::IndexSet<i32, cl_traits::doc_tests::FnvBuildHasher>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(2).unwrap();
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> TryWithCapacity for indexmap::IndexSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher + Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(indexmap::IndexSet::with_hasher(S::default()), input, |this: &mut Self| this
+      .try_reserve(input))
+  }
+}
+
+/// ```rust
+/// let structure: smallvec::SmallVec<[i32; 5]>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(10).unwrap();
+/// assert!(structure.capacity() >= 10);
+/// ```
+#[cfg(feature = "with-smallvec")]
+impl<A> TryWithCapacity for smallvec::SmallVec<A>
+where
+  A: smallvec::Array,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(smallvec::SmallVec::new(), input, |this: &mut Self| this.try_reserve_exact(input))
+  }
+}
+
+/// ```rust
+/// let structure: Result<staticvec::StaticVec<i32, 5>, _>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(1000);
+/// assert!(structure.is_err());
+/// ```
+#[cfg(feature = "with-staticvec")]
+impl<T, const N: usize> TryWithCapacity for staticvec::StaticVec<T, N> {
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(staticvec::StaticVec::new(), input)
+  }
+}
+
+/// ```rust
+/// let structure: Result<tinyvec::ArrayVec<[i32; 5]>, _>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(1000);
+/// assert!(structure.is_err());
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> TryWithCapacity for tinyvec::ArrayVec<A>
+where
+  A: Default + tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(tinyvec::ArrayVec::new(), input)
+  }
+}
+
+/// ```rust
+/// let structure: tinyvec::TinyVec<[i32; 5]>;
+/// structure = cl_traits::TryWithCapacity::try_with_capacity(10).unwrap();
+/// assert!(structure.capacity() >= 10);
+/// ```
+#[cfg(feature = "with-tinyvec")]
+impl<A> TryWithCapacity for tinyvec::TinyVec<A>
+where
+  A: Default + tinyvec::Array,
+  A::Item: Default,
+{
+  type Error = ReserveError;
+  type Input = usize;
+
+  #[inline]
+  fn try_with_capacity(input: Self::Input) -> Result<Self, Self::Error> {
+    manage!(tinyvec::TinyVec::new(), input, |this: &mut Self| {
+      crate::TryReserve::try_reserve(this, input)
+    })
+  }
+}
@@ -1,5 +1,12 @@
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{
+  boxed::Box,
+  collections::{BTreeMap, BTreeSet, VecDeque},
+  string::String,
+  vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// See [`with_capacity`](WithCapacity::with_capacity) for more information.
 pub trait WithCapacity {
@@ -10,6 +17,91 @@ pub trait WithCapacity {
   fn with_capacity(input: Self::Input) -> Self;
 }
 
+/// ```rust
+/// let structure: Box<Vec<i32>> = cl_traits::WithCapacity::with_capacity(2);
+/// assert_eq!(structure.capacity(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> WithCapacity for Box<T>
+where
+  T: WithCapacity,
+{
+  type Input = T::Input;
+
+  #[inline]
+  fn with_capacity(input: Self::Input) -> Self {
+    Box::new(T::with_capacity(input))
+  }
+}
+
+/// ```rust
+/// let structure: std::collections::BTreeMap<i32, i32> = cl_traits::WithCapacity::with_capacity(2);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<K, V> WithCapacity for BTreeMap<K, V>
+where
+  K: Ord,
+{
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(_: Self::Input) -> Self {
+    BTreeMap::new()
+  }
+}
+
+/// ```rust
+/// let structure: std::collections::BTreeSet<i32> = cl_traits::WithCapacity::with_capacity(2);
+/// assert_eq!(structure.len(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<V> WithCapacity for BTreeSet<V>
+where
+  V: Ord,
+{
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(_: Self::Input) -> Self {
+    BTreeSet::new()
+  }
+}
+
+/// ```rust
+/// let structure: std::collections::HashMap<i32, i32> = cl_traits::WithCapacity::with_capacity(2);
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "std")]
+impl<K, V> WithCapacity for HashMap<K, V>
+where
+  K: Eq + core::hash::Hash,
+{
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(input: Self::Input) -> Self {
+    HashMap::with_capacity(input)
+  }
+}
+
+/// ```rust
+/// let structure: std::collections::HashSet<i32> = cl_traits::WithCapacity::with_capacity(2);
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "std")]
+impl<V> WithCapacity for HashSet<V>
+where
+  V: Eq + core::hash::Hash,
+{
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(input: Self::Input) -> Self {
+    HashSet::with_capacity(input)
+  }
+}
+
 /// ```rust
 /// use cl_traits::Capacity;
 /// let structure: [i32; 5];
@@ -28,6 +120,21 @@ where
   }
 }
 
+/// ```rust
+/// use cl_traits::Capacity;
+/// let structure: cl_traits::ArrayStorage<i32, 5>;
+/// structure = cl_traits::WithCapacity::with_capacity(Default::default());
+/// assert_eq!(structure.capacity(), 5);
+/// ```
+impl<T, const N: usize> WithCapacity for crate::ArrayStorage<T, N> {
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(_: Self::Input) -> Self {
+    crate::ArrayStorage::new()
+  }
+}
+
 /// ```rust
 /// let structure: Option<i32> = cl_traits::WithCapacity::with_capacity(Default::default());
 /// assert_eq!(structure, None);
@@ -41,6 +148,20 @@ impl<T> WithCapacity for Option<T> {
   }
 }
 
+/// ```rust
+/// let structure: String = cl_traits::WithCapacity::with_capacity(2);
+/// assert_eq!(structure.capacity(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl WithCapacity for String {
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(input: Self::Input) -> Self {
+    String::with_capacity(input)
+  }
+}
+
 /// ```rust
 /// let structure: Vec<i32> = cl_traits::WithCapacity::with_capacity(2);
 /// assert_eq!(structure.capacity(), 2);
@@ -55,6 +176,35 @@ impl<T> WithCapacity for Vec<T> {
   }
 }
 
+/// ```rust
+/// let structure: std::collections::VecDeque<i32> = cl_traits::WithCapacity::with_capacity(2);
+/// assert!(structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> WithCapacity for VecDeque<T> {
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(input: Self::Input) -> Self {
+    VecDeque::with_capacity(input)
+  }
+}
+
+/// ```rust
+/// let structure: arrayvec::ArrayString<5>;
+/// structure = cl_traits::WithCapacity::with_capacity(Default::default());
+/// assert_eq!(structure.capacity(), 5);
+/// ```
+#[cfg(feature = "with-arrayvec")]
+impl<const CAP: usize> WithCapacity for arrayvec::ArrayString<CAP> {
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(_: Self::Input) -> Self {
+    arrayvec::ArrayString::new()
+  }
+}
+
 /// ```rust
 /// let structure: arrayvec::ArrayVec<i32, 5>;
 /// structure = cl_traits::WithCapacity::with_capacity(Default::default());
@@ -70,6 +220,147 @@ impl<T, const N: usize> WithCapacity for arrayvec::ArrayVec<T, N> {
   }
 }
 
+/// ```rust
+/// let structure: hashbrown::HashMap<i32, i32> = cl_traits::WithCapacity::with_capacity(2);
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<K, V, S> WithCapacity for hashbrown::HashMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher + Default,
+{
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(input: Self::Input) -> Self {
+    hashbrown::HashMap::with_capacity_and_hasher(input, S::default())
+  }
+}
+
+/// ```rust
+/// let structure: hashbrown::HashSet<i32> = cl_traits::WithCapacity::with_capacity(2);
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "with-hashbrown")]
+impl<V, S> WithCapacity for hashbrown::HashSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher + Default,
+{
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(input: Self::Input) -> Self {
+    hashbrown::HashSet::with_capacity_and_hasher(input, S::default())
+  }
+}
+
+/// ```rust
+/// use cl_traits::Capacity;
+/// let structure: heapless::Deque<i32, 5>;
+/// structure = cl_traits::WithCapacity::with_capacity(Default::default());
+/// assert_eq!(structure.capacity(), 5);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> WithCapacity for heapless::Deque<T, N> {
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(_: Self::Input) -> Self {
+    heapless::Deque::new()
+  }
+}
+
+/// ```rust
+/// use cl_traits::Capacity;
+/// let structure: heapless::FnvIndexMap<i32, i32, 8>;
+/// structure = cl_traits::WithCapacity::with_capacity(Default::default());
+/// assert_eq!(structure.capacity(), 8);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<K, V, S, const N: usize> WithCapacity for heapless::IndexMap<K, V, S, N>
+where
+  S: Default,
+{
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(_: Self::Input) -> Self {
+    Self::default()
+  }
+}
+
+/// ```rust
+/// use cl_traits::Capacity;
+/// let structure: heapless::String<10>;
+/// structure = cl_traits::WithCapacity::with_capacity(Default::default());
+/// assert_eq!(structure.capacity(), 10);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<const N: usize> WithCapacity for heapless::String<N> {
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(_: Self::Input) -> Self {
+    heapless::String::new()
+  }
+}
+
+/// ```rust
+/// use cl_traits::Capacity;
+/// let structure: heapless::Vec<i32, 5>;
+/// structure = cl_traits::WithCapacity::with_capacity(Default::default());
+/// assert_eq!(structure.capacity(), 5);
+/// ```
+#[cfg(feature = "with-heapless")]
+impl<T, const N: usize> WithCapacity for heapless::Vec<T, N> {
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(_: Self::Input) -> Self {
+    heapless::Vec::new()
+  }
+}
+
+/// ```rust
+/// let structure: indexmap::IndexMap<i32, i32, cl_traits::doc_tests::FnvBuildHasher>;
+/// structure = cl_traits::WithCapacity::with_capacity(2);
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<K, V, S> WithCapacity for indexmap::IndexMap<K, V, S>
+where
+  K: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher + Default,
+{
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(input: Self::Input) -> Self {
+    indexmap::IndexMap::with_capacity_and_hasher(input, S::default())
+  }
+}
+
+/// ```rust
+/// let structure: indexmap::IndexSet<i32, cl_traits::doc_tests::FnvBuildHasher>;
+/// structure = cl_traits::WithCapacity::with_capacity(2);
+/// assert!(structure.len() == 0 && structure.capacity() >= 2);
+/// ```
+#[cfg(feature = "with-indexmap")]
+impl<V, S> WithCapacity for indexmap::IndexSet<V, S>
+where
+  V: Eq + core::hash::Hash,
+  S: core::hash::BuildHasher + Default,
+{
+  type Input = usize;
+
+  #[inline]
+  fn with_capacity(input: Self::Input) -> Self {
+    indexmap::IndexSet::with_capacity_and_hasher(input, S::default())
+  }
+}
+
 /// ```rust
 /// let structure: smallvec::SmallVec<[i32; 5]>;
 /// structure = cl_traits::WithCapacity::with_capacity(Default::default());
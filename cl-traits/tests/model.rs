@@ -0,0 +1,274 @@
+//! Applies the same randomized sequences of operations to every backend and to a reference `Vec`,
+//! comparing contents and results after each step.
+
+use cl_traits::{
+  Clear, Insert, InsertError, InsertErrorReason, OutOfBounds, Push, Remove, Retain, Swap,
+  SwapOutOfBounds, Truncate,
+};
+use core::{convert::Infallible, fmt};
+
+const OPS: usize = 64;
+const SEEDS: u64 = 256;
+
+#[derive(Clone, Copy, Debug)]
+enum Op {
+  Clear,
+  Insert(usize, i32),
+  Push(i32),
+  Remove(usize),
+  Retain(i32),
+  Swap(usize, usize),
+  Truncate(usize),
+}
+
+#[derive(Debug, PartialEq)]
+enum Outcome {
+  Insert(Result<(), InsertError<i32>>),
+  Remove(Result<i32, OutOfBounds>),
+  Swap(Result<(), SwapOutOfBounds>),
+  Unit,
+}
+
+// Growable storages can't fail to push
+trait PushError {
+  fn into_insert_error(self) -> InsertError<i32>;
+}
+
+impl PushError for Infallible {
+  fn into_insert_error(self) -> InsertError<i32> {
+    match self {}
+  }
+}
+
+impl PushError for InsertError<i32> {
+  fn into_insert_error(self) -> InsertError<i32> {
+    self
+  }
+}
+
+// Reference implementation that is optionally bounded by a fixed capacity
+struct Model {
+  capacity: Option<usize>,
+  vec: Vec<i32>,
+}
+
+impl Model {
+  fn apply(&mut self, op: Op) -> Outcome {
+    let len = self.vec.len();
+    let is_full = self.capacity.filter(|&capacity| len >= capacity).is_some();
+    let overflow = |elem| {
+      let capacity = self.capacity.unwrap_or_default();
+      Outcome::Insert(Err(InsertError::new(elem, InsertErrorReason::CapacityExceeded { capacity })))
+    };
+    match op {
+      Op::Clear => {
+        self.vec.clear();
+        Outcome::Unit
+      }
+      Op::Insert(_, elem) if is_full => overflow(elem),
+      Op::Insert(idx, elem) if idx > len => Outcome::Insert(Err(InsertError::new(
+        elem,
+        InsertErrorReason::IndexOutOfBounds { index: idx, len },
+      ))),
+      Op::Insert(idx, elem) => {
+        self.vec.insert(idx, elem);
+        Outcome::Insert(Ok(()))
+      }
+      Op::Push(elem) if is_full => overflow(elem),
+      Op::Push(elem) => {
+        self.vec.push(elem);
+        Outcome::Insert(Ok(()))
+      }
+      Op::Remove(idx) if idx >= len => Outcome::Remove(Err(OutOfBounds { index: idx, len })),
+      Op::Remove(idx) => Outcome::Remove(Ok(self.vec.remove(idx))),
+      Op::Retain(modulus) => {
+        self.vec.retain(|elem| elem % modulus != 0);
+        Outcome::Unit
+      }
+      Op::Swap(a, b) if a >= len || b >= len => Outcome::Swap(Err(SwapOutOfBounds { a, b, len })),
+      Op::Swap(a, b) => {
+        self.vec.as_mut_slice().swap(a, b);
+        Outcome::Swap(Ok(()))
+      }
+      Op::Truncate(len) => {
+        self.vec.truncate(len);
+        Outcome::Unit
+      }
+    }
+  }
+}
+
+// xorshift64*, deterministic and good enough to generate operations
+struct Rng(u64);
+
+impl Rng {
+  fn new(seed: u64) -> Self {
+    Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+  }
+
+  fn next(&mut self) -> u64 {
+    self.0 ^= self.0 >> 12;
+    self.0 ^= self.0 << 25;
+    self.0 ^= self.0 >> 27;
+    self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+  }
+
+  // Number in `0..upper`
+  fn below(&mut self, upper: usize) -> usize {
+    (self.next() % upper as u64) as usize
+  }
+
+  // Index that is out of bounds from time to time
+  fn idx(&mut self, len: usize) -> usize {
+    self.below(len + 3)
+  }
+
+  fn op(&mut self, len: usize) -> Op {
+    let elem = self.below(100) as i32;
+    match self.below(16) {
+      0 => Op::Clear,
+      1..=3 => Op::Insert(self.idx(len), elem),
+      4..=8 => Op::Push(elem),
+      9..=10 => Op::Remove(self.idx(len)),
+      11 => Op::Retain(self.below(3) as i32 + 2),
+      12..=13 => Op::Swap(self.idx(len), self.idx(len)),
+      _ => Op::Truncate(self.idx(len)),
+    }
+  }
+}
+
+fn apply<'storage, S>(storage: &mut S, op: Op) -> Outcome
+where
+  S: Clear
+    + Insert<Error = InsertError<i32>, Input = (usize, i32), Ok = ()>
+    + Push<Input = i32, Ok = ()>
+    + Remove<Error = OutOfBounds, Input<'storage> = usize, Ok = i32>
+    + Retain<Input = i32, Output = ()>
+    + Swap<Input = [usize; 2], Output = Result<(), SwapOutOfBounds>>
+    + Truncate<Input = usize, Output = ()>
+    + 'storage,
+  <S as Push>::Error: PushError,
+{
+  match op {
+    Op::Clear => {
+      storage.clear();
+      Outcome::Unit
+    }
+    Op::Insert(idx, elem) => Outcome::Insert(Insert::insert(storage, (idx, elem))),
+    Op::Push(elem) => {
+      Outcome::Insert(Push::push(storage, elem).map_err(PushError::into_insert_error))
+    }
+    Op::Remove(idx) => Outcome::Remove(Remove::remove(storage, idx)),
+    Op::Retain(modulus) => {
+      Retain::retain(storage, |elem: &i32| elem % modulus != 0);
+      Outcome::Unit
+    }
+    Op::Swap(a, b) => Outcome::Swap(Swap::swap(storage, [a, b])),
+    Op::Truncate(len) => {
+      Truncate::truncate(storage, len);
+      Outcome::Unit
+    }
+  }
+}
+
+// Runs `SEEDS` sequences of `OPS` operations over instances created by `new`. `capacity` is
+// `None` for storages that grow on demand.
+fn check<'storage, S>(
+  name: &str,
+  capacity: Option<usize>,
+  new: impl Fn() -> S,
+  contents: impl Fn(&S) -> Vec<i32>,
+) where
+  S: Clear
+    + Insert<Error = InsertError<i32>, Input = (usize, i32), Ok = ()>
+    + Push<Input = i32, Ok = ()>
+    + Remove<Error = OutOfBounds, Input<'storage> = usize, Ok = i32>
+    + Retain<Input = i32, Output = ()>
+    + Swap<Input = [usize; 2], Output = Result<(), SwapOutOfBounds>>
+    + Truncate<Input = usize, Output = ()>
+    + 'storage,
+  <S as Push>::Error: PushError,
+{
+  for seed in 0..SEEDS {
+    let mut rng = Rng::new(seed);
+    let mut model = Model { capacity, vec: Vec::new() };
+    let mut storage = new();
+    let mut history = Vec::new();
+    for _ in 0..OPS {
+      let op = rng.op(model.vec.len());
+      history.push(op);
+      let expected = model.apply(op);
+      let actual = apply(&mut storage, op);
+      let ctx = Ctx { name, seed, history: &history };
+      assert_eq!(actual, expected, "{}", ctx);
+      assert_eq!(contents(&storage), model.vec, "{}", ctx);
+    }
+  }
+}
+
+struct Ctx<'any> {
+  history: &'any [Op],
+  name: &'any str,
+  seed: u64,
+}
+
+impl fmt::Display for Ctx<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "backend `{}` diverged with seed {} after {:?}", self.name, self.seed, self.history)
+  }
+}
+
+#[test]
+fn array_storage() {
+  check("ArrayStorage", Some(5), cl_traits::ArrayStorage::<i32, 5>::new, |s| s.to_vec());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec() {
+  check("Vec", None, Vec::<i32>::new, Clone::clone);
+}
+
+#[cfg(feature = "with-arrayvec")]
+#[test]
+fn arrayvec_array_vec() {
+  check("arrayvec::ArrayVec", Some(5), arrayvec::ArrayVec::<i32, 5>::new, |s| s.to_vec());
+}
+
+#[cfg(feature = "with-heapless")]
+#[test]
+fn heapless_deque() {
+  check("heapless::Deque", Some(5), heapless::Deque::<i32, 5>::new, |s| {
+    s.iter().copied().collect()
+  });
+}
+
+#[cfg(feature = "with-heapless")]
+#[test]
+fn heapless_vec() {
+  check("heapless::Vec", Some(5), heapless::Vec::<i32, 5>::new, |s| s.to_vec());
+}
+
+#[cfg(feature = "with-smallvec")]
+#[test]
+fn small_vec() {
+  check("SmallVec", None, smallvec::SmallVec::<[i32; 5]>::new, |s| s.to_vec());
+}
+
+#[cfg(feature = "with-staticvec")]
+#[test]
+fn static_vec() {
+  check("StaticVec", Some(5), staticvec::StaticVec::<i32, 5>::new, |s| s.iter().copied().collect());
+}
+
+#[cfg(feature = "with-tinyvec")]
+#[test]
+fn tiny_vec() {
+  check("TinyVec", None, tinyvec::TinyVec::<[i32; 5]>::new, |s| s.to_vec());
+}
+
+#[cfg(feature = "with-tinyvec")]
+#[test]
+fn tiny_vec_array_vec() {
+  check("tinyvec::ArrayVec", Some(5), tinyvec::ArrayVec::<[i32; 5]>::new, |s| s.to_vec());
+}
@@ -0,0 +1,80 @@
+//! Runs the built-in storages through `cl_traits::testing`.
+
+#![cfg(feature = "testing")]
+
+use cl_traits::testing::check_laws;
+
+#[test]
+fn array_storage() {
+  check_laws(&mut cl_traits::ArrayStorage::<i32, 5>::new(), 0..10);
+  check_laws(&mut cl_traits::doc_tests::array_storage(), 0..10);
+}
+
+#[test]
+fn option() {
+  check_laws(&mut None, 0..3);
+  check_laws(&mut Some(1), 0..3);
+}
+
+#[cfg(feature = "with-arrayvec")]
+#[test]
+fn arrayvec_array_vec() {
+  check_laws(&mut arrayvec::ArrayVec::<i32, 5>::new(), 0..10);
+  check_laws(&mut cl_traits::doc_tests::array_vec(), 0..10);
+}
+
+#[cfg(feature = "with-heapless")]
+#[test]
+fn heapless_deque() {
+  check_laws(&mut heapless::Deque::<i32, 5>::new(), 0..10);
+  check_laws(&mut cl_traits::doc_tests::heapless_deque(), 0..10);
+}
+
+#[cfg(feature = "with-heapless")]
+#[test]
+fn heapless_vec() {
+  check_laws(&mut heapless::Vec::<i32, 5>::new(), 0..10);
+  check_laws(&mut cl_traits::doc_tests::heapless_vec(), 0..10);
+}
+
+#[cfg(feature = "with-smallvec")]
+#[test]
+fn small_vec() {
+  check_laws(&mut smallvec::SmallVec::<[i32; 5]>::new(), 0..10);
+  check_laws(&mut cl_traits::doc_tests::small_vec(), 0..10);
+}
+
+#[cfg(feature = "with-staticvec")]
+#[test]
+fn static_vec() {
+  check_laws(&mut staticvec::StaticVec::<i32, 5>::new(), 0..10);
+  check_laws(&mut cl_traits::doc_tests::static_vec(), 0..10);
+}
+
+#[cfg(feature = "with-tinyvec")]
+#[test]
+fn tiny_vec() {
+  check_laws(&mut tinyvec::TinyVec::<[i32; 5]>::new(), 0..10);
+  check_laws(&mut cl_traits::doc_tests::tiny_vec(), 0..10);
+}
+
+#[cfg(feature = "with-tinyvec")]
+#[test]
+fn tiny_vec_array_vec() {
+  check_laws(&mut tinyvec::ArrayVec::<[i32; 5]>::new(), 0..10);
+  check_laws(&mut cl_traits::doc_tests::tiny_vec_array_vec(), 0..10);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec() {
+  check_laws(&mut Vec::new(), 0..10);
+  check_laws(&mut cl_traits::doc_tests::vec(), 0..10);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_deque() {
+  check_laws(&mut std::collections::VecDeque::new(), 0..10);
+  check_laws(&mut cl_traits::doc_tests::vec_deque(), 0..10);
+}
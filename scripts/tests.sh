@@ -15,8 +15,17 @@ $rt rustfmt
 
 $rt test-generic cl-traits
 $rt test-with-features cl-traits alloc
+$rt test-with-features cl-traits derive
 $rt test-with-features cl-traits std
+$rt test-with-features cl-traits testing
+$rt test-with-features cl-traits with-arbitrary
 $rt test-with-features cl-traits with-arrayvec
+$rt test-with-features cl-traits with-hashbrown
+$rt test-with-features cl-traits with-heapless
+$rt test-with-features cl-traits with-indexmap
+$rt test-with-features cl-traits with-proptest
+$rt test-with-features cl-traits with-serde
+$rt test-with-features cl-traits with-smallstr
 $rt test-with-features cl-traits with-smallvec
 $rt test-with-features cl-traits with-staticvec
 $rt test-with-features cl-traits with-tinyvec